@@ -0,0 +1,58 @@
+use super::word_entity::{ConjugationError, WordType};
+
+// a word to conjugate, the word type is searched or guessed when it is not given
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub word: String,
+    pub word_type: Option<WordType>,
+}
+
+// one word per line, optionally followed by its JMdict part of speech, e.g. 食べる v1,
+// blank lines and lines starting with # have no word
+pub fn parse_line(line: &str) -> Result<Option<Entry>, ConjugationError> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return Ok(None);
+    }
+    let mut columns = line.split_whitespace();
+    let word = columns.next().unwrap_or_default();
+    let tag: Vec<&str> = columns.collect();
+    let word_type = if tag.is_empty() {
+        None
+    } else {
+        Some(tag.join(" ").parse::<WordType>()?)
+    };
+    Ok(Some(Entry {
+        word: String::from(word),
+        word_type,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_line_test() {
+        assert_eq!(
+            parse_line("食べる"),
+            Ok(Some(Entry {
+                word: String::from("食べる"),
+                word_type: None,
+            }))
+        );
+        assert_eq!(
+            parse_line(" 入る\tv5r "),
+            Ok(Some(Entry {
+                word: String::from("入る"),
+                word_type: Some(WordType::VerbGodanR),
+            }))
+        );
+        assert_eq!(parse_line(""), Ok(None));
+        assert_eq!(parse_line("# verbs"), Ok(None));
+        assert_eq!(
+            parse_line("行く v9"),
+            Err(ConjugationError::UnsupportedWordType(String::from("v9")))
+        );
+    }
+}
@@ -1,16 +1,117 @@
-use clap::{App, Arg, ArgMatches};
+use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
 
 pub const WORD: &str = "WORD";
+pub const BATCH: &str = "batch";
+pub const DICTIONARY: &str = "dictionary";
+pub const JMDICT: &str = "jmdict";
+pub const SCRIPT: &str = "script";
+pub const EXPLAIN: &str = "explain";
+pub const FORMAT: &str = "format";
+pub const LONG: &str = "long";
+pub const KANJI: &str = "kanji";
+pub const KANA: &str = "kana";
+pub const ROMAJI: &str = "romaji";
+pub const TABLE: &str = "table";
+pub const JSON: &str = "json";
+pub const JSONL: &str = "jsonl";
+pub const CSV: &str = "csv";
+pub const TSV: &str = "tsv";
+pub const QUIZ: &str = "quiz";
+pub const TYPES: &str = "types";
+pub const FORMS: &str = "forms";
+pub const QUESTIONS: &str = "questions";
+pub const SEED: &str = "seed";
 
 pub fn app_arguments<'a>() -> ArgMatches<'a> {
     App::new("katsuyou")
         .version("0.0.1")
         .author("Karuna Murti <karuna.murti@gmail.com>")
         .about("katsuyou is a command line to show all conjugation form of a Japanese verb or adjective.")
+        .setting(AppSettings::SubcommandsNegateReqs)
                 .arg(Arg::with_name(WORD)
-                 .required(true)
+                 .required_unless(BATCH)
+                 .conflicts_with(BATCH)
                  .takes_value(true)
                  .index(1)
                  .help("Word (verb or adjective) to conjugate. Can be romaji, kanji, or kana.")
+                )
+                .arg(Arg::with_name(BATCH)
+                 .long(BATCH)
+                 .short("b")
+                 .takes_value(true)
+                 .value_name("FILE")
+                 .help("File with a word to conjugate on every line, optionally followed by its part of speech. - reads the standard input.")
+                )
+                .arg(Arg::with_name(DICTIONARY)
+                 .long(DICTIONARY)
+                 .short("d")
+                 .takes_value(true)
+                 .value_name("FILE")
+                 .help("Dictionary file to search instead of the bundled one.")
+                )
+                .arg(Arg::with_name(JMDICT)
+                 .long(JMDICT)
+                 .takes_value(true)
+                 .value_name("FILE")
+                 .conflicts_with(DICTIONARY)
+                 .help("JMdict XML file, plain or gzip compressed, to search instead of the bundled dictionary.")
+                )
+                .arg(Arg::with_name(SCRIPT)
+                 .long(SCRIPT)
+                 .short("s")
+                 .takes_value(true)
+                 .multiple(true)
+                 .use_delimiter(true)
+                 .possible_values(&[KANJI, KANA, ROMAJI])
+                 .default_value("kanji,kana")
+                 .help("Scripts to show every form in, separated by comma.")
+                )
+                .arg(Arg::with_name(EXPLAIN)
+                 .long(EXPLAIN)
+                 .help("Show how a conjugated word is derived from its dictionary form.")
+                )
+                .arg(Arg::with_name(FORMAT)
+                 .long(FORMAT)
+                 .short("f")
+                 .takes_value(true)
+                 .possible_values(&[TABLE, JSON, JSONL, CSV, TSV])
+                 .default_value(TABLE)
+                 .help("Output format, json, jsonl, csv and tsv list every form by its name.")
+                )
+                .arg(Arg::with_name(LONG)
+                 .long(LONG)
+                 .help("Print a csv or tsv row for every form instead of a column.")
+                )
+                .subcommand(SubCommand::with_name(QUIZ)
+                 .about("Asks for random forms of words from the dictionary, answered in kana or romaji.")
+                 .arg(Arg::with_name(TYPES)
+                  .long(TYPES)
+                  .short("t")
+                  .takes_value(true)
+                  .multiple(true)
+                  .use_delimiter(true)
+                  .value_name("TAG")
+                  .help("Parts of speech of the words to ask, separated by comma, e.g. v1,v5k.")
+                 )
+                 .arg(Arg::with_name(FORMS)
+                  .long(FORMS)
+                  .takes_value(true)
+                  .multiple(true)
+                  .use_delimiter(true)
+                  .value_name("FORM")
+                  .help("Forms to ask, separated by comma, e.g. perfective_form,formal_imperfective_negative_form.")
+                 )
+                 .arg(Arg::with_name(QUESTIONS)
+                  .long(QUESTIONS)
+                  .short("n")
+                  .takes_value(true)
+                  .default_value("10")
+                  .help("Number of questions.")
+                 )
+                 .arg(Arg::with_name(SEED)
+                  .long(SEED)
+                  .takes_value(true)
+                  .help("Seed to ask the same questions again.")
+                 )
                 ).get_matches()
 }
@@ -1,6 +1,8 @@
 pub const SURU: &str = "する";
 pub const KURU: &str = "来る";
 pub const KURU_KANA: &str = "くる";
+pub const ZURU: &str = "ずる";
+pub const JIRU: &str = "じる";
 pub const ARU: &str = "有る";
 pub const ARU_STEM: &str = "有";
 pub const ARU_KANA: &str = "ある";
@@ -22,6 +24,21 @@ pub const GODAN_U_END: &str = "う";
 pub const ICHIDAN_END: &str = "る";
 pub const ADJ_I_END: &str = "い";
 pub const ADJ_NA_END: &str = "な";
+// the kana of the i and e columns, ichidan verbs end with one of them followed by ru
+pub const I_COLUMN: &str = "いきぎしじちぢにひびぴみりゐ";
+pub const E_COLUMN: &str = "えけげせぜてでねへべぺめれゑ";
+// the stems and their readings of special suru verbs also conjugated like su verbs,
+// e.g. 愛さない
+pub const SU_FORM_STEMS: &[(&str, &str)] = &[
+    ("愛", "あい"),
+    ("介", "かい"),
+    ("害", "がい"),
+    ("解", "かい"),
+    ("課", "かい"),
+    ("罰", "ばっ"),
+    ("訳", "やく"),
+    ("略", "りゃく"),
+];
 // Negative
 pub const SHINAI: &str = "しない";
 pub const KUNAI: &str = "くない";
@@ -69,6 +86,10 @@ pub const SAIMASU: &str = "さいます";
 pub const SHAIMASU: &str = "しゃいます";
 // Formal Negative
 pub const SEN: &str = "せん";
+pub const KU_ARIMASEN: &str = "くありません";
+pub const DEWA_ARIMASEN: &str = "ではありません";
+// Formal Adjective
+pub const DESU: &str = "です";
 // FOrmal Past Negative
 pub const DESHITA: &str = "でした";
 // Potential
@@ -76,6 +97,7 @@ pub const RARERU: &str = "られる";
 pub const DEKIRU: &str = "できる";
 pub const KORARERU: &str = "こられる";
 pub const SARERU: &str = "される";
+pub const SERARERU: &str = "せられる";
 pub const SHARERU: &str = "しゃれる";
 pub const BERU: &str = "べる";
 pub const GERU: &str = "げる";
@@ -86,9 +108,115 @@ pub const RERU: &str = "れる";
 pub const SERU: &str = "せる";
 pub const TERU: &str = "てる";
 pub const ERU: &str = "える";
+// Imperative
+pub const SHIRO: &str = "しろ";
+pub const SEYO: &str = "せよ";
+pub const ZEYO: &str = "ぜよ";
+pub const KOI: &str = "こい";
+pub const KOYO: &str = "こよ";
+pub const SAI: &str = "さい";
+pub const SHAI: &str = "しゃい";
+pub const BE: &str = "べ";
+pub const GE: &str = "げ";
+pub const KE: &str = "け";
+pub const ME: &str = "め";
+pub const NE: &str = "ね";
+pub const RE: &str = "れ";
+pub const SE: &str = "せ";
+pub const TE: &str = "て";
+pub const E: &str = "え";
+pub const RO: &str = "ろ";
+pub const YO: &str = "よ";
+pub const NA: &str = "な";
+pub const NASAI: &str = "なさい";
+pub const MASE: &str = "ませ";
+pub const DE_KUDASAI: &str = "でください";
+// Volitional
+pub const SHIYOU: &str = "しよう";
+pub const KOYOU: &str = "こよう";
+pub const SAROU: &str = "さろう";
+pub const SHAROU: &str = "しゃろう";
+pub const BOU: &str = "ぼう";
+pub const GOU: &str = "ごう";
+pub const KOU: &str = "こう";
+pub const MOU: &str = "もう";
+pub const NOU: &str = "のう";
+pub const ROU: &str = "ろう";
+pub const SOU: &str = "そう";
+pub const TOU: &str = "とう";
+pub const OU: &str = "おう";
+pub const YOU: &str = "よう";
+pub const KAROU: &str = "かろう";
+pub const DAROU: &str = "だろう";
+pub const MASHOU: &str = "ましょう";
+// Negative Volitional
+pub const MAI: &str = "まい";
+pub const SHIMAI: &str = "しまい";
+pub const KOMAI: &str = "こまい";
+pub const KU_ARUMAI: &str = "くあるまい";
+pub const DEWA_ARUMAI: &str = "ではあるまい";
+// Te
+pub const DA: &str = "だ";
+pub const DE: &str = "で";
+pub const KUTE: &str = "くて";
+// Progressive
+pub const IRU: &str = "いる";
+// Conditional
+pub const SUREBA: &str = "すれば";
+pub const ZUREBA: &str = "ずれば";
+pub const KUREBA: &str = "くれば";
+pub const BA: &str = "ば";
+pub const REBA: &str = "れば";
+pub const KEREBA: &str = "ければ";
+pub const NARA: &str = "なら";
+pub const RA: &str = "ら";
+// Causative
+pub const SASERU: &str = "させる";
+pub const KOSASERU: &str = "こさせる";
+pub const SASU: &str = "さす";
+pub const KOSASU: &str = "こさす";
+// Taru Adjective
+pub const TARU: &str = "たる";
+pub const TO: &str = "と";
+pub const TOSHITE: &str = "として";
+pub const TOSHITEIRU: &str = "としている";
+// Classical
+// the a, i, u and e kana of a row, e.g. か, き, く, け
+pub const A_ROW: [&str; 4] = ["あ", "い", "う", "え"];
+pub const KA_ROW: [&str; 4] = ["か", "き", "く", "け"];
+pub const GA_ROW: [&str; 4] = ["が", "ぎ", "ぐ", "げ"];
+pub const SA_ROW: [&str; 4] = ["さ", "し", "す", "せ"];
+pub const ZA_ROW: [&str; 4] = ["ざ", "じ", "ず", "ぜ"];
+pub const TA_ROW: [&str; 4] = ["た", "ち", "つ", "て"];
+pub const DA_ROW: [&str; 4] = ["だ", "ぢ", "づ", "で"];
+pub const NA_ROW: [&str; 4] = ["な", "に", "ぬ", "ね"];
+pub const HA_ROW: [&str; 4] = ["は", "ひ", "ふ", "へ"];
+pub const BA_ROW: [&str; 4] = ["ば", "び", "ぶ", "べ"];
+pub const MA_ROW: [&str; 4] = ["ま", "み", "む", "め"];
+pub const YA_ROW: [&str; 4] = ["や", "い", "ゆ", "え"];
+pub const RA_ROW: [&str; 4] = ["ら", "り", "る", "れ"];
+pub const WA_ROW: [&str; 4] = ["わ", "ゐ", "う", "ゑ"];
+pub const NIDAN_D_END: &str = "づ";
+pub const NIDAN_H_END: &str = "ふ";
+pub const NIDAN_Y_END: &str = "ゆ";
+pub const NIDAN_Z_END: &str = "ず";
+pub const RAHEN_END: &str = "り";
+pub const ADJ_KU_END: &str = "し";
+pub const BUNGO_RU: &str = "る";
+pub const BUNGO_RE: &str = "れ";
+pub const BUNGO_YO: &str = "よ";
+pub const BUNGO_ZU: &str = "ず";
+pub const BUNGO_MU: &str = "む";
+pub const BUNGO_KARA: &str = "から";
+pub const BUNGO_KU: &str = "く";
+pub const BUNGO_KI: &str = "き";
+pub const BUNGO_KERE: &str = "けれ";
+pub const BUNGO_KARE: &str = "かれ";
+// Keigo
+pub const O: &str = "お";
+pub const NI_NARU: &str = "になる";
+pub const NASARU: &str = "なさる";
+pub const ITASU: &str = "いたす";
 // MISC
-pub const ADJ_II_KANA: &str = "い";
 pub const ADJ_YOI_KANA: &str = "よ";
-pub const ADJ_YOI_KANJI: &str = "良";
 pub const NOT_APPLICABLE: &str = "-";
-pub const TODO: &str = "TODO";
@@ -0,0 +1,161 @@
+use super::form::Form;
+use super::printer::{self, Scripts};
+use super::word_entity::{ConjugationError, WordEntity};
+
+const WORD_COLUMNS: [&str; 4] = ["dictionary_form", "reading", "translation", "word_type"];
+const LONG_COLUMNS: [&str; 4] = ["dictionary_form", "reading", "form", "value"];
+
+// comma or tab separated values, a column for every form or a row for every form
+#[derive(Debug, Clone, Copy)]
+pub struct Table {
+    pub separator: char,
+    pub long: bool,
+}
+
+impl Table {
+    // forms are named after the conjugation methods, e.g. formal_perfective_form
+    pub fn header(self) -> String {
+        if self.long {
+            return self.row(LONG_COLUMNS.iter().map(|column| String::from(*column)));
+        }
+        let columns = WORD_COLUMNS
+            .iter()
+            .cloned()
+            .chain(Form::all().map(Form::name));
+        self.row(columns.map(String::from))
+    }
+
+    // forms that do not exist for the word type are empty, or left out in the long table
+    pub fn rows(
+        self,
+        word: &WordEntity,
+        scripts: &Scripts,
+    ) -> Result<Vec<String>, ConjugationError> {
+        let mut forms: Vec<(Form, Option<String>)> = vec![];
+        for form in Form::all() {
+            let conjugation = word.conjugation(form)?;
+            forms.push((
+                form,
+                conjugation.map(|conjugation| printer::rendered(&conjugation, scripts)),
+            ));
+        }
+        if self.long {
+            return Ok(forms
+                .into_iter()
+                .filter_map(|(form, value)| {
+                    let fields = [
+                        word.dictionary_form.clone(),
+                        word.reading.clone(),
+                        String::from(form.name()),
+                        value?,
+                    ];
+                    Some(self.row(fields.iter().cloned()))
+                })
+                .collect());
+        }
+        let mut fields = vec![
+            word.dictionary_form.clone(),
+            word.reading.clone(),
+            word.translation.clone(),
+            String::from(word.word_type.name()),
+        ];
+        fields.extend(
+            forms
+                .into_iter()
+                .map(|(_, value)| value.unwrap_or_default()),
+        );
+        Ok(vec![self.row(fields.into_iter())])
+    }
+
+    fn row(self, fields: impl Iterator<Item = String>) -> String {
+        fields
+            .map(|field| self.field(&field))
+            .collect::<Vec<String>>()
+            .join(&self.separator.to_string())
+    }
+
+    // tab separated values cannot be quoted, tabs and line breaks become spaces
+    fn field(self, field: &str) -> String {
+        if self.separator == '\t' {
+            return field.replace(&['\t', '\n', '\r'][..], " ");
+        }
+        if field.contains(&[self.separator, '"', '\n', '\r'][..]) {
+            return format!("\"{}\"", field.replace('"', "\"\""));
+        }
+        String::from(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::word_entity::WordType;
+    use super::*;
+
+    const KANJI: Scripts = Scripts {
+        kanji: true,
+        kana: false,
+        romaji: false,
+    };
+
+    fn word() -> WordEntity {
+        WordEntity {
+            dictionary_form: String::from("読む"),
+            reading: String::from("よむ"),
+            translation: String::from("to read, to study"),
+            word_type: WordType::VerbGodanM,
+        }
+    }
+
+    #[test]
+    fn wide_table_test() {
+        let table = Table {
+            separator: ',',
+            long: false,
+        };
+        let header = table.header();
+        assert!(header.starts_with(
+            "dictionary_form,reading,translation,word_type,imperfective_form,imperfective_negative_form,"
+        ));
+        let rows = table.rows(&word(), &KANJI).unwrap();
+        assert_eq!(rows.len(), 1);
+        assert!(rows[0].starts_with("読む,よむ,\"to read, to study\",godan-m,読む,読まない,"));
+        assert_eq!(rows[0].split(',').count(), header.split(',').count() + 1);
+        let table = Table {
+            separator: '\t',
+            long: false,
+        };
+        let rows = table.rows(&word(), &KANJI).unwrap();
+        assert!(rows[0].starts_with("読む\tよむ\tto read, to study\tgodan-m\t"));
+    }
+
+    #[test]
+    fn long_table_test() {
+        let table = Table {
+            separator: '\t',
+            long: true,
+        };
+        assert_eq!(table.header(), "dictionary_form\treading\tform\tvalue");
+        let scripts = Scripts {
+            kanji: true,
+            kana: true,
+            romaji: true,
+        };
+        let rows = table.rows(&word(), &scripts).unwrap();
+        // modern verbs have every form but the classical and adverbial ones
+        let modern = Form::all()
+            .filter(|form| !form.is_classical() && *form != Form::Adverbial)
+            .count();
+        assert_eq!(rows.len(), modern);
+        assert_eq!(
+            rows[6],
+            "読む\tよむ\tformal_perfective_form\t読みました / よみました / yomimashita"
+        );
+        let adjective = WordEntity {
+            dictionary_form: String::from("高い"),
+            reading: String::from("たかい"),
+            translation: String::from("tall"),
+            word_type: WordType::AdjectiveI,
+        };
+        assert!(table.rows(&adjective, &scripts).unwrap().len() < rows.len());
+    }
+}
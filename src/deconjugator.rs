@@ -0,0 +1,407 @@
+use super::constant::*;
+use super::derivation::Transformation;
+use super::word_entity::{WordEntity, WordType};
+use std::iter;
+
+// stands for the part of the word that does not change
+const STEM: &str = "〇";
+// a word is not derived more than this many times, e.g. causative passive
+const MAX_DERIVATIONS: usize = 3;
+
+const WORD_TYPES: &[WordType] = &[
+    WordType::VerbSuru,
+    WordType::VerbSuruS,
+    WordType::VerbSuruC,
+    WordType::VerbZuru,
+    WordType::VerbKuru,
+    WordType::VerbGodanARU,
+    WordType::VerbGodanB,
+    WordType::VerbGodanG,
+    WordType::VerbGodanK,
+    WordType::VerbGodanKS,
+    WordType::VerbGodanM,
+    WordType::VerbGodanN,
+    WordType::VerbGodanR,
+    WordType::VerbGodanRI,
+    WordType::VerbGodanS,
+    WordType::VerbGodanT,
+    WordType::VerbGodanU,
+    WordType::VerbGodanUS,
+    WordType::VerbIchidan,
+    WordType::AdjectiveI,
+    WordType::AdjectiveNa,
+    WordType::AdjectiveTaru,
+    WordType::AdverbTo,
+    WordType::Auxiliary,
+    WordType::AuxiliaryAdjective,
+    WordType::VerbYodanB,
+    WordType::VerbYodanG,
+    WordType::VerbYodanH,
+    WordType::VerbYodanK,
+    WordType::VerbYodanM,
+    WordType::VerbYodanN,
+    WordType::VerbYodanR,
+    WordType::VerbYodanS,
+    WordType::VerbYodanT,
+    WordType::VerbNidanLowerA,
+    WordType::VerbNidanUpperB,
+    WordType::VerbNidanLowerB,
+    WordType::VerbNidanUpperD,
+    WordType::VerbNidanLowerD,
+    WordType::VerbNidanUpperG,
+    WordType::VerbNidanLowerG,
+    WordType::VerbNidanUpperH,
+    WordType::VerbNidanLowerH,
+    WordType::VerbNidanUpperK,
+    WordType::VerbNidanLowerK,
+    WordType::VerbNidanUpperM,
+    WordType::VerbNidanLowerM,
+    WordType::VerbNidanLowerN,
+    WordType::VerbNidanUpperR,
+    WordType::VerbNidanLowerR,
+    WordType::VerbNidanLowerS,
+    WordType::VerbNidanUpperT,
+    WordType::VerbNidanLowerT,
+    WordType::VerbNidanLowerW,
+    WordType::VerbNidanUpperY,
+    WordType::VerbNidanLowerY,
+    WordType::VerbNidanLowerZ,
+    WordType::VerbRahen,
+    WordType::VerbNahen,
+    WordType::AdjectiveKu,
+    WordType::AdjectiveShiku,
+];
+
+// a dictionary form the inflected word could come from,
+// the transformations are listed in the order they are applied
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candidate {
+    pub dictionary_form: String,
+    pub word_type: WordType,
+    pub transformations: Vec<Transformation>,
+}
+
+// the inflected ending replaced by the dictionary ending of the word type,
+// the inflected word conjugates like inflected_type when it is a derived word
+#[derive(Debug, PartialEq)]
+struct Rule {
+    inflected_ending: String,
+    dictionary_ending: String,
+    word_type: WordType,
+    inflected_type: Option<WordType>,
+    transformation: Transformation,
+}
+
+pub struct Deconjugator {
+    rules: Vec<Rule>,
+}
+
+lazy_static! {
+    static ref BUNDLED: Deconjugator = Deconjugator::new();
+}
+
+impl Default for Deconjugator {
+    fn default() -> Deconjugator {
+        Deconjugator::new()
+    }
+}
+
+impl Deconjugator {
+    // the rules are the endings produced by the conjugation methods,
+    // so every form that can be conjugated can be deconjugated
+    pub fn new() -> Deconjugator {
+        let mut rules: Vec<Rule> = vec![];
+        for word_type in WORD_TYPES.iter() {
+            for template in templates(*word_type) {
+                let word = WordEntity {
+                    dictionary_form: template,
+                    reading: String::new(),
+                    translation: String::new(),
+                    word_type: *word_type,
+                };
+                let derivations = [
+                    (Transformation::Passive, word.passive()),
+                    (Transformation::Causative, word.causative()),
+                    (Transformation::ShortCausative, word.short_causative()),
+                ];
+                for (transformation, derived) in derivations.iter() {
+                    if let Ok(Some(derived)) = derived {
+                        let rule = Rule::new(
+                            &word,
+                            &derived.dictionary_form,
+                            Some(derived.word_type),
+                            *transformation,
+                        );
+                        push_rule(&mut rules, rule);
+                    }
+                }
+                let forms = word.forms().expect("templates should be conjugable");
+                for (form, inflected) in forms {
+                    let rule = Rule::new(&word, &inflected, None, Transformation::Form(form));
+                    push_rule(&mut rules, rule);
+                }
+            }
+        }
+        Deconjugator { rules }
+    }
+
+    // the rules only depend on the conjugation methods, so they are built once
+    pub fn bundled() -> &'static Deconjugator {
+        &BUNDLED
+    }
+
+    // every dictionary form the word could be inflected from,
+    // whether the dictionary form exists is up to the dictionary
+    pub fn deconjugate(&self, word: &str) -> Vec<Candidate> {
+        let mut candidates: Vec<Candidate> = vec![];
+        self.deconjugate_into(word, None, &[], &mut candidates);
+        candidates
+    }
+
+    fn deconjugate_into(
+        &self,
+        word: &str,
+        word_type: Option<WordType>,
+        transformations: &[Transformation],
+        candidates: &mut Vec<Candidate>,
+    ) {
+        if transformations.len() > MAX_DERIVATIONS {
+            return;
+        }
+        for rule in self.rules.iter() {
+            if !word.ends_with(&rule.inflected_ending) {
+                continue;
+            }
+            // a conjugated form is never derived again,
+            // a derived word is only undone by the derivation of its word type
+            match word_type {
+                Some(word_type) if rule.inflected_type != Some(word_type) => continue,
+                _ => {}
+            }
+            let stem = &word[..word.len() - rule.inflected_ending.len()];
+            let candidate = Candidate {
+                dictionary_form: [stem, &rule.dictionary_ending].join(""),
+                word_type: rule.word_type,
+                transformations: [&[rule.transformation], transformations].concat(),
+            };
+            if candidates.contains(&candidate) {
+                continue;
+            }
+            candidates.push(candidate.clone());
+            self.deconjugate_into(
+                &candidate.dictionary_form,
+                Some(candidate.word_type),
+                &candidate.transformations,
+                candidates,
+            );
+        }
+    }
+}
+
+pub fn deconjugate(word: &str) -> Vec<Candidate> {
+    Deconjugator::bundled().deconjugate(word)
+}
+
+impl Rule {
+    // the common stem is left out of both endings
+    fn new(
+        word: &WordEntity,
+        inflected: &str,
+        inflected_type: Option<WordType>,
+        transformation: Transformation,
+    ) -> Option<Rule> {
+        let stem = if word.dictionary_form.starts_with(STEM) {
+            STEM
+        } else {
+            ""
+        };
+        if !inflected.starts_with(stem) {
+            return None;
+        }
+        let inflected_ending = &inflected[stem.len()..];
+        let dictionary_ending = &word.dictionary_form[stem.len()..];
+        if inflected_ending.is_empty() || inflected_ending == dictionary_ending {
+            return None;
+        }
+        Some(Rule {
+            inflected_ending: String::from(inflected_ending),
+            dictionary_ending: String::from(dictionary_ending),
+            word_type: word.word_type,
+            inflected_type,
+            transformation,
+        })
+    }
+}
+
+fn push_rule(rules: &mut Vec<Rule>, rule: Option<Rule>) {
+    if let Some(rule) = rule {
+        if !rules.contains(&rule) {
+            rules.push(rule);
+        }
+    }
+}
+
+// dictionary forms covering every ending the word type is conjugated with,
+// irregular words are spelled out
+fn templates(word_type: WordType) -> Vec<String> {
+    let endings: &[&str] = match word_type {
+        WordType::VerbSuru => &[SURU],
+        // the verbs also taking su forms are spelled out
+        WordType::VerbSuruS => {
+            let words = SU_FORM_STEMS
+                .iter()
+                .flat_map(|(kanji, kana)| vec![[kanji, SURU].join(""), [kana, SURU].join("")]);
+            return iter::once([STEM, SURU].join("")).chain(words).collect();
+        }
+        WordType::VerbSuruC => &[GODAN_S_END],
+        WordType::VerbZuru => &[ZURU],
+        WordType::VerbKuru => return vec![String::from(KURU), String::from(KURU_KANA)],
+        WordType::VerbGodanARU => &[GODAN_ARU_SA_END, GODAN_ARU_SHA_END, GODAN_ARU_RU_END],
+        WordType::VerbGodanB => &[GODAN_B_END],
+        WordType::VerbGodanG => &[GODAN_G_END],
+        WordType::VerbGodanK => &[GODAN_K_END],
+        WordType::VerbGodanKS => &[GODAN_K_END],
+        WordType::VerbGodanM => &[GODAN_M_END],
+        WordType::VerbGodanN => &[GODAN_N_END],
+        WordType::VerbGodanR => &[GODAN_R_END],
+        WordType::VerbGodanRI => return vec![String::from(ARU), String::from(ARU_KANA)],
+        WordType::VerbGodanS => &[GODAN_S_END],
+        WordType::VerbGodanT => &[GODAN_T_END],
+        WordType::VerbGodanU => &[GODAN_U_END],
+        WordType::VerbGodanUS => &[GODAN_U_END],
+        WordType::VerbIchidan => &[ICHIDAN_END],
+        WordType::AdjectiveI => &[ADJ_I_END],
+        WordType::AdjectiveNa => &["", ADJ_NA_END],
+        WordType::AdjectiveTaru => &[TARU],
+        WordType::AdverbTo => &[""],
+        WordType::Auxiliary => &[DA],
+        WordType::AuxiliaryAdjective => &[ADJ_I_END],
+        WordType::VerbYodanB => &[GODAN_B_END],
+        WordType::VerbYodanG => &[GODAN_G_END],
+        WordType::VerbYodanH => &[NIDAN_H_END],
+        WordType::VerbYodanK => &[GODAN_K_END],
+        WordType::VerbYodanM => &[GODAN_M_END],
+        WordType::VerbYodanN => &[GODAN_N_END],
+        WordType::VerbYodanR => &[GODAN_R_END],
+        WordType::VerbYodanS => &[GODAN_S_END],
+        WordType::VerbYodanT => &[GODAN_T_END],
+        WordType::VerbNidanLowerA => &[GODAN_U_END],
+        WordType::VerbNidanUpperB => &[GODAN_B_END],
+        WordType::VerbNidanLowerB => &[GODAN_B_END],
+        WordType::VerbNidanUpperD => &[NIDAN_D_END],
+        WordType::VerbNidanLowerD => &[NIDAN_D_END],
+        WordType::VerbNidanUpperG => &[GODAN_G_END],
+        WordType::VerbNidanLowerG => &[GODAN_G_END],
+        WordType::VerbNidanUpperH => &[NIDAN_H_END],
+        WordType::VerbNidanLowerH => &[NIDAN_H_END],
+        WordType::VerbNidanUpperK => &[GODAN_K_END],
+        WordType::VerbNidanLowerK => &[GODAN_K_END],
+        WordType::VerbNidanUpperM => &[GODAN_M_END],
+        WordType::VerbNidanLowerM => &[GODAN_M_END],
+        WordType::VerbNidanLowerN => &[GODAN_N_END],
+        WordType::VerbNidanUpperR => &[GODAN_R_END],
+        WordType::VerbNidanLowerR => &[GODAN_R_END],
+        WordType::VerbNidanLowerS => &[GODAN_S_END],
+        WordType::VerbNidanUpperT => &[GODAN_T_END],
+        WordType::VerbNidanLowerT => &[GODAN_T_END],
+        WordType::VerbNidanLowerW => &[GODAN_U_END],
+        WordType::VerbNidanUpperY => &[NIDAN_Y_END],
+        WordType::VerbNidanLowerY => &[NIDAN_Y_END],
+        WordType::VerbNidanLowerZ => &[NIDAN_Z_END],
+        WordType::VerbRahen => &[RAHEN_END],
+        WordType::VerbNahen => &[GODAN_N_END],
+        WordType::AdjectiveKu => &[ADJ_KU_END],
+        WordType::AdjectiveShiku => &[ADJ_KU_END],
+        // conjugated like the verb it ends with
+        WordType::AuxiliaryVerb => return vec![],
+    };
+    endings
+        .iter()
+        .map(|ending| [STEM, ending].join(""))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::form::Form;
+    use super::super::form::Polarity::*;
+    use super::super::form::Politeness::*;
+    use super::super::form::Tense::*;
+    use super::*;
+
+    fn find<'a>(candidates: &'a [Candidate], word: &str, word_type: WordType) -> &'a Candidate {
+        candidates
+            .iter()
+            .find(|candidate| candidate.dictionary_form == word && candidate.word_type == word_type)
+            .unwrap_or_else(|| panic!("{} should be a candidate", word))
+    }
+
+    #[test]
+    fn deconjugate_test() {
+        let deconjugator = Deconjugator::new();
+        let candidates = deconjugator.deconjugate("食べさせられなかった");
+        let candidate = find(&candidates, "食べる", WordType::VerbIchidan);
+        assert_eq!(
+            candidate.transformations,
+            vec![
+                Transformation::Causative,
+                Transformation::Passive,
+                Transformation::Form(Form::Indicative(Past, Negative, Plain)),
+            ]
+        );
+        let words = [
+            ("読みませんでした", "読む", WordType::VerbGodanM),
+            ("行って", "行く", WordType::VerbGodanKS),
+            ("書かされる", "書く", WordType::VerbGodanK),
+            ("こなかった", "来る", WordType::VerbKuru),
+            ("こさせる", "くる", WordType::VerbKuru),
+            ("勉強しよう", "勉強する", WordType::VerbSuru),
+            ("愛せる", "愛する", WordType::VerbSuruS),
+            ("察しない", "察する", WordType::VerbSuruS),
+            ("愛せる", "愛す", WordType::VerbSuruC),
+            ("信じなかった", "信ずる", WordType::VerbZuru),
+            ("有った", "有る", WordType::VerbGodanRI),
+            ("いらっしゃいました", "いらっしゃる", WordType::VerbGodanARU),
+            ("高くなかった", "高い", WordType::AdjectiveI),
+            ("静かだった", "静か", WordType::AdjectiveNa),
+            ("泳いでいます", "泳ぐ", WordType::VerbGodanG),
+            ("堂々としていた", "堂々たる", WordType::AdjectiveTaru),
+            ("らしくない", "らしい", WordType::AuxiliaryAdjective),
+            ("死ぬる", "死ぬ", WordType::VerbNahen),
+            ("行かむ", "行く", WordType::VerbYodanK),
+            ("美しき", "美し", WordType::AdjectiveShiku),
+            ("受くれ", "受く", WordType::VerbNidanLowerK),
+        ];
+        for (inflected, word, word_type) in words.iter() {
+            find(&deconjugator.deconjugate(inflected), word, *word_type);
+        }
+    }
+
+    #[test]
+    fn conjugate_back_test() {
+        let deconjugator = Deconjugator::new();
+        for candidate in deconjugator.deconjugate("待たせられます") {
+            let mut word = WordEntity {
+                dictionary_form: candidate.dictionary_form.clone(),
+                reading: String::new(),
+                translation: String::new(),
+                word_type: candidate.word_type,
+            };
+            let mut inflected = word.dictionary_form.clone();
+            for transformation in candidate.transformations.iter() {
+                let derived = match transformation {
+                    Transformation::Passive => word.passive(),
+                    Transformation::Causative => word.causative(),
+                    Transformation::ShortCausative => word.short_causative(),
+                    Transformation::Form(form) => {
+                        inflected = word.conjugate(*form).unwrap().unwrap();
+                        continue;
+                    }
+                };
+                word = derived.unwrap().unwrap();
+                inflected = word.dictionary_form.clone();
+            }
+            assert_eq!(inflected, "待たせられます", "{:?}", candidate);
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use super::form::Form;
+use super::word_entity::{Conjugation, ConjugationError, WordEntity};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Transformation {
+    Passive,
+    Causative,
+    ShortCausative,
+    Form(Form),
+}
+
+impl Transformation {
+    // grammatical name, e.g. causative, polite negative past
+    pub fn name(self) -> &'static str {
+        match self {
+            Transformation::Passive => "passive",
+            Transformation::Causative => "causative",
+            Transformation::ShortCausative => "short causative",
+            Transformation::Form(form) => form.description(),
+        }
+    }
+}
+
+// the word after one transformation
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+    pub transformation: Transformation,
+    pub conjugation: Conjugation,
+}
+
+// how a word is derived from its dictionary form, one step at a time
+#[derive(Debug, Clone, PartialEq)]
+pub struct Derivation {
+    pub word: WordEntity,
+    pub dictionary_form: Conjugation,
+    pub steps: Vec<Step>,
+}
+
+impl Derivation {
+    // None when a transformation does not apply to the word,
+    // a conjugation form can only be the last transformation
+    pub fn new(
+        word: &WordEntity,
+        transformations: &[Transformation],
+    ) -> Result<Option<Derivation>, ConjugationError> {
+        let dictionary_form = match word.conjugation(Form::DICTIONARY)? {
+            Some(dictionary_form) => dictionary_form,
+            None => return Ok(None),
+        };
+        let mut derived = word.clone();
+        let mut steps: Vec<Step> = vec![];
+        for (position, transformation) in transformations.iter().enumerate() {
+            let form = match transformation {
+                Transformation::Form(form) => {
+                    if position + 1 != transformations.len() {
+                        return Ok(None);
+                    }
+                    *form
+                }
+                _ => {
+                    let voice = match transformation {
+                        Transformation::Passive => derived.passive()?,
+                        Transformation::Causative => derived.causative()?,
+                        _ => derived.short_causative()?,
+                    };
+                    derived = match voice {
+                        Some(voice) => voice,
+                        None => return Ok(None),
+                    };
+                    Form::DICTIONARY
+                }
+            };
+            let conjugation = match derived.conjugation(form)? {
+                Some(conjugation) => conjugation,
+                None => return Ok(None),
+            };
+            steps.push(Step {
+                transformation: *transformation,
+                conjugation,
+            });
+        }
+        Ok(Some(Derivation {
+            word: word.clone(),
+            dictionary_form,
+            steps,
+        }))
+    }
+
+    // the form the word is derived to
+    pub fn conjugation(&self) -> Conjugation {
+        match self.steps.last() {
+            Some(step) => step.conjugation.clone(),
+            None => self.dictionary_form.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::form::Polarity::*;
+    use super::super::form::Politeness::*;
+    use super::super::form::Tense::*;
+    use super::super::word_entity::WordType;
+    use super::*;
+
+    #[test]
+    fn derivation_test() {
+        let word = WordEntity {
+            dictionary_form: String::from("読む"),
+            reading: String::from("よむ"),
+            translation: String::from("to read"),
+            word_type: WordType::VerbGodanM,
+        };
+        let derivation = Derivation::new(
+            &word,
+            &[
+                Transformation::Causative,
+                Transformation::Passive,
+                Transformation::Form(Form::Indicative(Past, Negative, Polite)),
+            ],
+        )
+        .unwrap()
+        .unwrap();
+        let steps: Vec<(&str, &str)> = derivation
+            .steps
+            .iter()
+            .map(|step| (step.transformation.name(), step.conjugation.kanji.as_str()))
+            .collect();
+        assert_eq!(
+            steps,
+            vec![
+                ("causative", "読ませる"),
+                ("passive", "読ませられる"),
+                ("polite negative past", "読ませられませんでした"),
+            ]
+        );
+        assert_eq!(derivation.conjugation().romaji, "yomaseraremasendeshita");
+        let form = Transformation::Form(Form::Indicative(Past, Affirmative, Plain));
+        assert_eq!(
+            Derivation::new(&word, &[form, Transformation::Passive]),
+            Ok(None)
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use super::constant::SURU;
+use super::form::Form;
+use super::word_entity::{WordEntity, WordType};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const BUNDLED_DICTIONARY: &str = include_str!("../data/dictionary.tsv");
+
+#[derive(Debug, Default)]
+pub struct Dictionary {
+    entries: Vec<WordEntity>,
+    index: HashMap<String, Vec<usize>>,
+}
+
+impl Dictionary {
+    pub fn new() -> Dictionary {
+        Dictionary::default()
+    }
+
+    // JMdict/EDICT subset shipped with the crate
+    pub fn bundled() -> Dictionary {
+        Dictionary::from_tsv(BUNDLED_DICTIONARY).expect("bundled dictionary should be well formed")
+    }
+
+    // local file in the same format as data/dictionary.tsv
+    pub fn load(path: &Path) -> io::Result<Dictionary> {
+        let content = fs::read_to_string(path)?;
+        Dictionary::from_tsv(&content)
+    }
+
+    // one word per line: kanji, reading, part of speech, translation
+    // lines with a part of speech that is not a known word type are skipped
+    pub fn from_tsv(content: &str) -> io::Result<Dictionary> {
+        let mut dictionary = Dictionary::new();
+        for (number, line) in content.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let columns: Vec<&str> = line.split('\t').collect();
+            if columns.len() != 4 || columns[1].is_empty() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("malformed dictionary line {}: {}", number + 1, line),
+                ));
+            }
+            let word_type = match WordType::from_edict_tag(columns[2]) {
+                Some(word_type) => word_type,
+                None => continue,
+            };
+            let dictionary_form = if columns[0].is_empty() {
+                columns[1]
+            } else {
+                columns[0]
+            };
+            dictionary.insert(
+                WordEntity {
+                    dictionary_form: String::from(dictionary_form),
+                    reading: String::from(columns[1]),
+                    translation: String::from(columns[3]),
+                    word_type,
+                },
+                &[columns[0], columns[1]],
+            );
+        }
+        Ok(dictionary)
+    }
+
+    // the word is searchable by its dictionary form, its plain form and every extra key
+    pub fn insert(&mut self, word: WordEntity, keys: &[&str]) {
+        let position = self.entries.len();
+        let mut all_keys = vec![
+            word.dictionary_form.clone(),
+            word.conjugate(Form::DICTIONARY)
+                .ok()
+                .flatten()
+                .unwrap_or_default(),
+        ];
+        for key in keys.iter() {
+            all_keys.push(String::from(*key));
+            // JMdict lists suru verbs as the noun alone
+            if word.word_type == WordType::VerbSuru && !key.ends_with(SURU) {
+                all_keys.push([key, SURU].join(""));
+            }
+        }
+        for key in all_keys {
+            if key.is_empty() {
+                continue;
+            }
+            let positions = self.index.entry(key).or_default();
+            if !positions.contains(&position) {
+                positions.push(position);
+            }
+        }
+        self.entries.push(word);
+    }
+
+    pub fn lookup(&self, word: &str) -> Vec<&WordEntity> {
+        match self.index.get(word) {
+            Some(positions) => positions
+                .iter()
+                .map(|&position| &self.entries[position])
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &WordEntity> {
+        self.entries.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
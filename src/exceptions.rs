@@ -0,0 +1,150 @@
+use super::word_entity::{WordEntity, WordType};
+use std::io;
+
+const BUNDLED_EXCEPTIONS: &str = include_str!("../data/exceptions.tsv");
+
+lazy_static! {
+    static ref BUNDLED: Exceptions =
+        Exceptions::from_tsv(BUNDLED_EXCEPTIONS).expect("bundled exceptions should be well formed");
+}
+
+// a verb whose type cannot be told from its ending
+#[derive(Debug, Clone, PartialEq)]
+pub struct Exception {
+    pub dictionary_form: String,
+    pub reading: String,
+    pub word_type: WordType,
+}
+
+impl Exception {
+    // the exception has no translation
+    pub fn word_entity(&self) -> WordEntity {
+        WordEntity {
+            dictionary_form: self.dictionary_form.clone(),
+            reading: self.reading.clone(),
+            translation: String::new(),
+            word_type: self.word_type,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Exceptions {
+    entries: Vec<Exception>,
+}
+
+impl Exceptions {
+    // godan verbs ending in iru or eru shipped with the crate, parsed once
+    pub fn bundled() -> &'static Exceptions {
+        &BUNDLED
+    }
+
+    // one word per line: kanji, reading, part of speech
+    pub fn from_tsv(content: &str) -> io::Result<Exceptions> {
+        let mut entries: Vec<Exception> = vec![];
+        for (number, line) in content.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let columns: Vec<&str> = line.split('\t').collect();
+            let word_type = match columns.get(2) {
+                Some(tag) if columns.len() == 3 && !columns[1].is_empty() => {
+                    WordType::from_edict_tag(tag)
+                }
+                _ => None,
+            };
+            let word_type = match word_type {
+                Some(word_type) => word_type,
+                None => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("malformed exception line {}: {}", number + 1, line),
+                    ))
+                }
+            };
+            let dictionary_form = if columns[0].is_empty() {
+                columns[1]
+            } else {
+                columns[0]
+            };
+            entries.push(Exception {
+                dictionary_form: String::from(dictionary_form),
+                reading: String::from(columns[1]),
+                word_type,
+            });
+        }
+        Ok(Exceptions { entries })
+    }
+
+    // entries written or read as the word, a known reading decides between homographs,
+    // e.g. 着る and 切る are both read kiru
+    pub fn lookup(&self, word: &str, reading: Option<&str>) -> Vec<&Exception> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.dictionary_form == word || entry.reading == word)
+            .filter(|entry| reading.is_none_or(|reading| entry.reading == reading))
+            .collect()
+    }
+
+    // the reading is shared with a word of another type, e.g. 要る and 居る
+    pub fn is_ambiguous(&self, exception: &Exception) -> bool {
+        self.entries.iter().any(|entry| {
+            entry.reading == exception.reading && entry.word_type != exception.word_type
+        })
+    }
+
+    pub fn ambiguous(&self) -> Vec<&Exception> {
+        self.entries
+            .iter()
+            .filter(|entry| self.is_ambiguous(entry))
+            .collect()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Exception> {
+        self.entries.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word_types(exceptions: &[&Exception]) -> Vec<WordType> {
+        exceptions.iter().map(|entry| entry.word_type).collect()
+    }
+
+    #[test]
+    fn lookup_test() {
+        let exceptions = Exceptions::bundled();
+        assert_eq!(
+            word_types(&exceptions.lookup("入る", None)),
+            vec![WordType::VerbGodanR]
+        );
+        assert_eq!(
+            word_types(&exceptions.lookup("きる", None)),
+            vec![
+                WordType::VerbGodanR,
+                WordType::VerbGodanR,
+                WordType::VerbIchidan
+            ]
+        );
+        assert_eq!(
+            word_types(&exceptions.lookup("着る", Some("きる"))),
+            vec![WordType::VerbIchidan]
+        );
+        assert!(exceptions.lookup("着る", Some("つける")).is_empty());
+        assert!(exceptions.lookup("食べる", None).is_empty());
+    }
+
+    #[test]
+    fn ambiguous_test() {
+        let exceptions = Exceptions::bundled();
+        let ambiguous = exceptions.ambiguous();
+        for word in ["要る", "居る", "切る", "着る", "帰る", "変える"].iter() {
+            assert!(ambiguous.iter().any(|entry| entry.dictionary_form == *word));
+        }
+        let known = exceptions.lookup("走る", None);
+        assert!(!exceptions.is_ambiguous(known[0]));
+        assert!(Exceptions::from_tsv("入る\tはいる\tv9\n").is_err());
+    }
+}
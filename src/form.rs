@@ -0,0 +1,385 @@
+use self::Polarity::*;
+use self::Politeness::*;
+use self::Tense::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tense {
+    NonPast,
+    Past,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Polarity {
+    Affirmative,
+    Negative,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Politeness {
+    Plain,
+    Polite,
+}
+
+// the six bases of a classical word, e.g. 未然形 for the irrealis base
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Base {
+    Irrealis,
+    Continuative,
+    Terminal,
+    Attributive,
+    Realis,
+    Imperative,
+}
+
+// every conjugation of a word, by mood and by the tense, polarity and politeness
+// the mood is conjugated with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Form {
+    Indicative(Tense, Polarity, Politeness),
+    // the informal negative with desu, e.g. takakunai desu
+    NaiDesu(Tense),
+    Potential(Tense, Polarity, Politeness),
+    Imperative(Polarity, Politeness),
+    WrittenImperative,
+    Volitional(Polarity, Politeness),
+    // mai attached to the stem, e.g. tabemai
+    ShortNegativeVolitional,
+    Te(Polarity),
+    Nakute,
+    // the adverbial form of a taru adjective or to adverb, e.g. 堂々と
+    Adverbial,
+    Progressive(Tense, Polarity, Politeness),
+    Provisional(Polarity),
+    Conditional(Polarity),
+    Hypothetical(Polarity),
+    // a base of a classical word, e.g. the attributive 死ぬる
+    Classical(Base),
+    // zu attached to the irrealis base, e.g. 行かず
+    ClassicalNegative,
+    // mu attached to the irrealis base, e.g. 行かむ
+    ClassicalVolitional,
+}
+
+// every form with its stable identifier and its grammatical name
+const FORMS: &[(Form, &str, &str)] = &[
+    (
+        Form::Indicative(NonPast, Affirmative, Plain),
+        "imperfective_form",
+        "non-past",
+    ),
+    (
+        Form::Indicative(NonPast, Negative, Plain),
+        "imperfective_negative_form",
+        "negative",
+    ),
+    (
+        Form::Indicative(Past, Affirmative, Plain),
+        "perfective_form",
+        "past",
+    ),
+    (
+        Form::Indicative(Past, Negative, Plain),
+        "perfective_negative_form",
+        "negative past",
+    ),
+    (
+        Form::Indicative(NonPast, Affirmative, Polite),
+        "formal_imperfective_form",
+        "polite",
+    ),
+    (
+        Form::Indicative(NonPast, Negative, Polite),
+        "formal_imperfective_negative_form",
+        "polite negative",
+    ),
+    (
+        Form::Indicative(Past, Affirmative, Polite),
+        "formal_perfective_form",
+        "polite past",
+    ),
+    (
+        Form::Indicative(Past, Negative, Polite),
+        "formal_perfective_negative_form",
+        "polite negative past",
+    ),
+    (
+        Form::NaiDesu(NonPast),
+        "formal_imperfective_negative_variant_form",
+        "polite negative nai desu",
+    ),
+    (
+        Form::NaiDesu(Past),
+        "formal_perfective_negative_variant_form",
+        "polite negative past nakatta desu",
+    ),
+    (
+        Form::Potential(NonPast, Affirmative, Plain),
+        "informal_potential_form",
+        "potential",
+    ),
+    (
+        Form::Potential(NonPast, Negative, Plain),
+        "informal_potential_negative_form",
+        "negative potential",
+    ),
+    (
+        Form::Potential(Past, Affirmative, Plain),
+        "informal_perfective_potential_form",
+        "past potential",
+    ),
+    (
+        Form::Potential(Past, Negative, Plain),
+        "informal_perfective_potential_negative_form",
+        "negative past potential",
+    ),
+    (
+        Form::Potential(NonPast, Affirmative, Polite),
+        "formal_potential_form",
+        "polite potential",
+    ),
+    (
+        Form::Potential(NonPast, Negative, Polite),
+        "formal_potential_negative_form",
+        "polite negative potential",
+    ),
+    (
+        Form::Potential(Past, Affirmative, Polite),
+        "formal_perfective_potential_form",
+        "polite past potential",
+    ),
+    (
+        Form::Potential(Past, Negative, Polite),
+        "formal_perfective_potential_negative_form",
+        "polite negative past potential",
+    ),
+    (
+        Form::Imperative(Affirmative, Plain),
+        "informal_imperative_form",
+        "imperative",
+    ),
+    (
+        Form::Imperative(Negative, Plain),
+        "informal_imperative_negative_form",
+        "negative imperative",
+    ),
+    (
+        Form::Imperative(Affirmative, Polite),
+        "formal_imperative_form",
+        "polite imperative",
+    ),
+    (
+        Form::Imperative(Negative, Polite),
+        "formal_imperative_negative_form",
+        "polite negative imperative",
+    ),
+    (
+        Form::WrittenImperative,
+        "written_imperative_form",
+        "written imperative",
+    ),
+    (
+        Form::Volitional(Affirmative, Plain),
+        "volitional_form",
+        "volitional",
+    ),
+    (
+        Form::Volitional(Negative, Plain),
+        "volitional_negative_form",
+        "negative volitional",
+    ),
+    (
+        Form::Volitional(Affirmative, Polite),
+        "formal_volitional_form",
+        "polite volitional",
+    ),
+    (
+        Form::Volitional(Negative, Polite),
+        "formal_volitional_negative_form",
+        "polite negative volitional",
+    ),
+    (
+        Form::ShortNegativeVolitional,
+        "volitional_negative_variant_form",
+        "short negative volitional",
+    ),
+    (Form::Te(Affirmative), "te_form", "te form"),
+    (Form::Te(Negative), "negative_te_form", "negative te form"),
+    (Form::Nakute, "negative_te_variant_form", "nakute form"),
+    (Form::Adverbial, "adverbial_form", "adverbial"),
+    (
+        Form::Progressive(NonPast, Affirmative, Plain),
+        "progressive_form",
+        "progressive",
+    ),
+    (
+        Form::Progressive(NonPast, Negative, Plain),
+        "progressive_negative_form",
+        "negative progressive",
+    ),
+    (
+        Form::Progressive(Past, Affirmative, Plain),
+        "perfective_progressive_form",
+        "past progressive",
+    ),
+    (
+        Form::Progressive(Past, Negative, Plain),
+        "perfective_progressive_negative_form",
+        "negative past progressive",
+    ),
+    (
+        Form::Progressive(NonPast, Affirmative, Polite),
+        "formal_progressive_form",
+        "polite progressive",
+    ),
+    (
+        Form::Progressive(NonPast, Negative, Polite),
+        "formal_progressive_negative_form",
+        "polite negative progressive",
+    ),
+    (
+        Form::Progressive(Past, Affirmative, Polite),
+        "formal_perfective_progressive_form",
+        "polite past progressive",
+    ),
+    (
+        Form::Progressive(Past, Negative, Polite),
+        "formal_perfective_progressive_negative_form",
+        "polite negative past progressive",
+    ),
+    (
+        Form::Provisional(Affirmative),
+        "provisional_form",
+        "provisional",
+    ),
+    (
+        Form::Provisional(Negative),
+        "provisional_negative_form",
+        "negative provisional",
+    ),
+    (
+        Form::Conditional(Affirmative),
+        "conditional_form",
+        "conditional",
+    ),
+    (
+        Form::Conditional(Negative),
+        "conditional_negative_form",
+        "negative conditional",
+    ),
+    (
+        Form::Hypothetical(Affirmative),
+        "hypothetical_form",
+        "hypothetical",
+    ),
+    (
+        Form::Hypothetical(Negative),
+        "hypothetical_negative_form",
+        "negative hypothetical",
+    ),
+    (
+        Form::Classical(Base::Irrealis),
+        "classical_irrealis_form",
+        "irrealis (mizenkei)",
+    ),
+    (
+        Form::Classical(Base::Continuative),
+        "classical_continuative_form",
+        "continuative (ren'youkei)",
+    ),
+    (
+        Form::Classical(Base::Terminal),
+        "classical_terminal_form",
+        "terminal (shuushikei)",
+    ),
+    (
+        Form::Classical(Base::Attributive),
+        "classical_attributive_form",
+        "attributive (rentaikei)",
+    ),
+    (
+        Form::Classical(Base::Realis),
+        "classical_realis_form",
+        "realis (izenkei)",
+    ),
+    (
+        Form::Classical(Base::Imperative),
+        "classical_imperative_form",
+        "imperative (meireikei)",
+    ),
+    (
+        Form::ClassicalNegative,
+        "classical_negative_form",
+        "classical negative",
+    ),
+    (
+        Form::ClassicalVolitional,
+        "classical_volitional_form",
+        "classical volitional",
+    ),
+];
+
+impl Form {
+    // the dictionary form itself
+    pub const DICTIONARY: Form = Form::Indicative(NonPast, Affirmative, Plain);
+
+    pub fn all() -> impl Iterator<Item = Form> {
+        FORMS.iter().map(|(form, _, _)| *form)
+    }
+
+    // identifier of the form, e.g. formal_perfective_negative_form
+    pub fn name(self) -> &'static str {
+        FORMS[self.position()].1
+    }
+
+    // grammatical name of the form, e.g. polite negative past
+    pub fn description(self) -> &'static str {
+        FORMS[self.position()].2
+    }
+
+    // the forms only classical words have
+    pub fn is_classical(self) -> bool {
+        matches!(
+            self,
+            Form::Classical(_) | Form::ClassicalNegative | Form::ClassicalVolitional
+        )
+    }
+
+    pub fn from_name(name: &str) -> Option<Form> {
+        FORMS
+            .iter()
+            .find(|(_, form_name, _)| *form_name == name)
+            .map(|(form, _, _)| *form)
+    }
+
+    fn position(self) -> usize {
+        FORMS
+            .iter()
+            .position(|(form, _, _)| *form == self)
+            .expect("every form should be listed")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn form_test() {
+        let form = Form::Indicative(Past, Negative, Polite);
+        assert_eq!(form.name(), "formal_perfective_negative_form");
+        assert_eq!(form.description(), "polite negative past");
+        assert_eq!(Form::from_name(form.name()), Some(form));
+        assert_eq!(Form::from_name("dictionary_form"), None);
+        // 3 moods in 8 combinations and 2 in 4, the rest by tense, polarity or alone,
+        // then the classical bases and the two forms built on the irrealis base
+        assert_eq!(Form::all().count(), 3 * 8 + 2 * 4 + 5 * 2 + 4 + 6 + 2);
+        for form in Form::all() {
+            assert_eq!(
+                Form::all()
+                    .filter(|other| other.name() == form.name())
+                    .count(),
+                1
+            );
+        }
+    }
+}
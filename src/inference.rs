@@ -0,0 +1,272 @@
+use super::constant::*;
+use super::exceptions::{Exception, Exceptions};
+use super::romaji;
+use super::word_entity::WordType;
+
+// words whose type cannot be told from their ending
+const SPECIAL_WORDS: &[(&str, WordType)] = &[
+    ("行く", WordType::VerbGodanKS),
+    ("逝く", WordType::VerbGodanKS),
+    ("いく", WordType::VerbGodanKS),
+    ("ゆく", WordType::VerbGodanKS),
+    ("有る", WordType::VerbGodanRI),
+    ("在る", WordType::VerbGodanRI),
+    ("ある", WordType::VerbGodanRI),
+    ("来る", WordType::VerbKuru),
+    ("くる", WordType::VerbKuru),
+    ("出来る", WordType::VerbIchidan),
+    ("できる", WordType::VerbIchidan),
+    ("為る", WordType::VerbSuru),
+    ("する", WordType::VerbSuru),
+    ("いらっしゃる", WordType::VerbGodanARU),
+    ("下さる", WordType::VerbGodanARU),
+    ("くださる", WordType::VerbGodanARU),
+    ("なさる", WordType::VerbGodanARU),
+    ("仰る", WordType::VerbGodanARU),
+    ("おっしゃる", WordType::VerbGodanARU),
+    ("御座る", WordType::VerbGodanARU),
+    ("ござる", WordType::VerbGodanARU),
+    ("問う", WordType::VerbGodanUS),
+    ("請う", WordType::VerbGodanUS),
+    ("乞う", WordType::VerbGodanUS),
+];
+
+// kana of a listed verb that are more often another word, e.g. こう is mostly the adverb
+const UNLIKELY_WORDS: &[(&str, WordType)] = &[
+    ("とう", WordType::VerbGodanUS),
+    ("こう", WordType::VerbGodanUS),
+];
+
+// compounds keep the type of the word they end with, e.g. 持って行く
+const COMPOUND_ENDINGS: &[(&str, WordType)] = &[
+    ("行く", WordType::VerbGodanKS),
+    ("来る", WordType::VerbKuru),
+    ("下さる", WordType::VerbGodanARU),
+];
+
+// the ending alone decides the godan type
+const GODAN_ENDINGS: &[(&str, WordType)] = &[
+    (GODAN_B_END, WordType::VerbGodanB),
+    (GODAN_G_END, WordType::VerbGodanG),
+    (GODAN_K_END, WordType::VerbGodanK),
+    (GODAN_M_END, WordType::VerbGodanM),
+    (GODAN_N_END, WordType::VerbGodanN),
+    (GODAN_S_END, WordType::VerbGodanS),
+    (GODAN_T_END, WordType::VerbGodanT),
+    (GODAN_U_END, WordType::VerbGodanU),
+];
+
+// a guess less likely than this is not used to conjugate the word
+pub const MIN_CONFIDENCE: f64 = 0.6;
+
+// the most likely word type, confidence is between 0 and 1
+#[derive(Debug, Clone, PartialEq)]
+pub struct Inference {
+    pub word_type: WordType,
+    pub confidence: f64,
+    // the other word types the word could have, most likely first
+    pub alternatives: Vec<(WordType, f64)>,
+}
+
+// the reading is used when the dictionary form is written with kanji
+pub fn infer_word_type(dictionary_form: &str, reading: Option<&str>) -> Option<Inference> {
+    let dictionary_form = dictionary_form.trim();
+    if dictionary_form.is_empty() {
+        return None;
+    }
+    let reading = reading.map(str::trim).filter(|reading| !reading.is_empty());
+    let kana = reading.unwrap_or(dictionary_form);
+    // the exceptions are checked first, their type cannot be guessed
+    let exceptions = Exceptions::bundled();
+    let entries = exceptions.lookup(dictionary_form, reading);
+    let guesses = if entries.is_empty() {
+        guess(exceptions, dictionary_form, kana)
+    } else {
+        shared(&entries)
+    };
+    let mut guesses = guesses.into_iter();
+    let (word_type, confidence) = guesses.next()?;
+    Some(Inference {
+        word_type,
+        confidence,
+        alternatives: guesses.collect(),
+    })
+}
+
+// every possible word type with its likelihood, most likely first, only the listed words
+// are certain and a word that does not end like a verb or adjective has none
+fn guess(exceptions: &Exceptions, dictionary_form: &str, kana: &str) -> Vec<(WordType, f64)> {
+    for (word, word_type) in SPECIAL_WORDS.iter() {
+        if dictionary_form == *word || kana == *word {
+            return vec![(*word_type, 1.0)];
+        }
+    }
+    for (word, word_type) in UNLIKELY_WORDS.iter() {
+        if kana == *word {
+            return vec![(*word_type, 0.4)];
+        }
+    }
+    for (ending, word_type) in COMPOUND_ENDINGS.iter() {
+        if dictionary_form.ends_with(ending) {
+            return vec![(*word_type, 0.9)];
+        }
+    }
+    // a compound ending with an exception written in kanji, e.g. 立ち入る
+    for entry in exceptions.iter() {
+        if entry.dictionary_form != entry.reading
+            && dictionary_form.len() > entry.dictionary_form.len()
+            && dictionary_form.ends_with(&entry.dictionary_form)
+        {
+            return vec![(entry.word_type, 0.9)];
+        }
+    }
+    match ending_verb_type(kana) {
+        // the kana in front of zuru or suru can also be a godan verb stem, e.g. kosuru
+        Some(WordType::VerbZuru) => vec![(WordType::VerbZuru, 0.9), (WordType::VerbGodanR, 0.1)],
+        Some(WordType::VerbSuru) => vec![(WordType::VerbSuru, 0.9), (WordType::VerbGodanR, 0.1)],
+        Some(WordType::VerbIchidan) => {
+            vec![(WordType::VerbIchidan, 0.8), (WordType::VerbGodanR, 0.2)]
+        }
+        Some(word_type) => vec![(word_type, 0.9)],
+        None if kana.ends_with(ADJ_I_END) => {
+            // kirei and kirai are na adjectives
+            vec![(WordType::AdjectiveI, 0.85), (WordType::AdjectiveNa, 0.15)]
+        }
+        None if kana.ends_with(ADJ_NA_END) => {
+            vec![(WordType::AdjectiveNa, 0.9), (WordType::VerbSuru, 0.1)]
+        }
+        // a kanji can be read either way
+        None if kana.ends_with(ICHIDAN_END) => {
+            vec![(WordType::VerbIchidan, 0.5), (WordType::VerbGodanR, 0.5)]
+        }
+        // a bare noun or an inflected word
+        None => vec![],
+    }
+}
+
+// homographs of different types are equally likely, in the order of the table
+fn shared(entries: &[&Exception]) -> Vec<(WordType, f64)> {
+    let mut word_types: Vec<WordType> = vec![];
+    for entry in entries.iter() {
+        if !word_types.contains(&entry.word_type) {
+            word_types.push(entry.word_type);
+        }
+    }
+    let confidence = 1.0 / word_types.len() as f64;
+    word_types
+        .into_iter()
+        .map(|word_type| (word_type, confidence))
+        .collect()
+}
+
+// the type of a word ending with a listed word, e.g. the auxiliary verb ていく
+pub fn special_ending_type(kana: &str) -> Option<WordType> {
+    SPECIAL_WORDS
+        .iter()
+        .find(|(word, _)| kana.ends_with(word))
+        .map(|(_, word_type)| *word_type)
+}
+
+// the verb type told by the ending alone, a kanji before ru can be read either way
+pub fn ending_verb_type(kana: &str) -> Option<WordType> {
+    // zuru is the older form of jiru, e.g. 信ずる
+    if kana.ends_with(ZURU) {
+        return Some(WordType::VerbZuru);
+    }
+    if kana.ends_with(SURU) {
+        return Some(WordType::VerbSuru);
+    }
+    for (ending, word_type) in GODAN_ENDINGS.iter() {
+        if kana.ends_with(ending) {
+            return Some(*word_type);
+        }
+    }
+    if !kana.ends_with(ICHIDAN_END) {
+        return None;
+    }
+    match kana.chars().rev().nth(1).map(romaji::to_hiragana) {
+        Some(c) if I_COLUMN.contains(c) || E_COLUMN.contains(c) => Some(WordType::VerbIchidan),
+        Some(c) if is_kana(c) => Some(WordType::VerbGodanR),
+        _ => None,
+    }
+}
+
+fn is_kana(c: char) -> bool {
+    matches!(c, 'ぁ'..='ゖ' | 'ァ'..='ヺ' | 'ー')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word_type(dictionary_form: &str, reading: Option<&str>) -> WordType {
+        infer_word_type(dictionary_form, reading).unwrap().word_type
+    }
+
+    #[test]
+    fn infer_word_type_test() {
+        let words = [
+            ("食べる", Some("たべる"), WordType::VerbIchidan),
+            ("ググる", None, WordType::VerbGodanR),
+            ("わかる", None, WordType::VerbGodanR),
+            ("入る", None, WordType::VerbGodanR),
+            ("立ち入る", None, WordType::VerbGodanR),
+            ("しくじる", None, WordType::VerbGodanR),
+            ("切る", Some("きる"), WordType::VerbGodanR),
+            ("着る", Some("きる"), WordType::VerbIchidan),
+            ("書く", None, WordType::VerbGodanK),
+            ("持って行く", None, WordType::VerbGodanKS),
+            ("やって来る", None, WordType::VerbKuru),
+            ("出来る", None, WordType::VerbIchidan),
+            ("有る", None, WordType::VerbGodanRI),
+            ("いらっしゃる", None, WordType::VerbGodanARU),
+            ("問う", None, WordType::VerbGodanUS),
+            ("勉強する", None, WordType::VerbSuru),
+            ("信ずる", None, WordType::VerbZuru),
+            ("エモい", None, WordType::AdjectiveI),
+            ("静かな", None, WordType::AdjectiveNa),
+        ];
+        for (dictionary_form, reading, expected) in words.iter() {
+            assert_eq!(
+                word_type(dictionary_form, *reading),
+                *expected,
+                "{}",
+                dictionary_form
+            );
+        }
+        assert!(infer_word_type(" ", None).is_none());
+        for garbage in ["xyzzy", "ことば", "勉強", "ググった"].iter() {
+            assert!(infer_word_type(garbage, None).is_none(), "{}", garbage);
+        }
+    }
+
+    #[test]
+    fn confidence_test() {
+        let inference = infer_word_type("見る", None).unwrap();
+        assert_eq!(inference.alternatives.len(), 1);
+        assert!(inference.confidence <= 0.5);
+        let inference = infer_word_type("見る", Some("みる")).unwrap();
+        assert_eq!(inference.word_type, WordType::VerbIchidan);
+        assert!(inference.confidence > 0.5);
+        assert_eq!(inference.alternatives[0].0, WordType::VerbGodanR);
+        let inference = infer_word_type("いる", None).unwrap();
+        assert_eq!(inference.word_type, WordType::VerbIchidan);
+        assert_eq!(inference.alternatives[0].0, WordType::VerbGodanR);
+        assert!(inference.confidence < 1.0);
+        let inference = infer_word_type("要る", Some("いる")).unwrap();
+        assert_eq!(inference.word_type, WordType::VerbGodanR);
+        assert!(inference.alternatives.is_empty());
+        let inference = infer_word_type("読む", None).unwrap();
+        assert!(inference.alternatives.is_empty());
+        assert!(inference.confidence < 1.0);
+        let inference = infer_word_type("ググる", None).unwrap();
+        assert!(inference.confidence >= MIN_CONFIDENCE && inference.confidence < 1.0);
+        let inference = infer_word_type("来る", None).unwrap();
+        assert_eq!(inference.confidence, 1.0);
+        let inference = infer_word_type("こう", None).unwrap();
+        assert_eq!(inference.word_type, WordType::VerbGodanUS);
+        assert!(inference.confidence < MIN_CONFIDENCE);
+        let inference = infer_word_type("請う", Some("こう")).unwrap();
+        assert_eq!(inference.confidence, 1.0);
+    }
+}
@@ -0,0 +1,288 @@
+use super::dictionary::Dictionary;
+use super::word_entity::{WordEntity, WordType};
+use flate2::read::GzDecoder;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+// usually written using kana alone
+const USUALLY_KANA: &str = "uk";
+
+#[derive(Default)]
+struct Sense {
+    pos: Vec<String>,
+    glosses: Vec<String>,
+    usually_kana: bool,
+}
+
+#[derive(Default)]
+struct Entry {
+    kanji: Vec<String>,
+    readings: Vec<String>,
+    senses: Vec<Sense>,
+}
+
+// JMdict XML, either plain or gzip compressed like JMdict_e.gz
+pub fn load(path: &Path) -> io::Result<Dictionary> {
+    let file = File::open(path)?;
+    if path.extension().is_some_and(|extension| extension == "gz") {
+        return read(BufReader::new(GzDecoder::new(file)));
+    }
+    read(BufReader::new(file))
+}
+
+// entries are streamed one at a time, only the ones with a part of speech
+// matching a word type are kept
+pub fn read<R: BufRead>(reader: R) -> io::Result<Dictionary> {
+    let mut dictionary = Dictionary::new();
+    // JMdict can be distributed with the part of speech entities expanded,
+    // the declarations map the expanded text back to the entity name
+    let mut entities: HashMap<String, String> = HashMap::new();
+    let mut entry = Entry::default();
+    for line in reader.lines() {
+        let line = line?;
+        let line = line.trim();
+        if line.starts_with("<!ENTITY") {
+            if let Some((name, description)) = parse_entity_declaration(line) {
+                entities.insert(description, name);
+            }
+            continue;
+        }
+        for (tag, attributes, text) in parse_elements(line) {
+            match tag {
+                "entry" => entry = Entry::default(),
+                "/entry" => add_entry(&mut dictionary, &entry),
+                "sense" => entry.senses.push(Sense::default()),
+                "keb" => entry.kanji.push(unescape(text)),
+                "reb" => entry.readings.push(unescape(text)),
+                "pos" | "misc" => {
+                    let name = match entity_name(text) {
+                        Some(name) => String::from(name),
+                        None => match entities.get(&unescape(text)) {
+                            Some(name) => name.clone(),
+                            None => continue,
+                        },
+                    };
+                    if let Some(sense) = entry.senses.last_mut() {
+                        if tag == "misc" {
+                            sense.usually_kana |= name == USUALLY_KANA;
+                        } else {
+                            sense.pos.push(name);
+                        }
+                    }
+                }
+                "gloss" => {
+                    if !is_english(attributes) {
+                        continue;
+                    }
+                    if let Some(sense) = entry.senses.last_mut() {
+                        sense.glosses.push(unescape(text));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(dictionary)
+}
+
+// one word entity for every word type of the entry
+fn add_entry(dictionary: &mut Dictionary, entry: &Entry) {
+    if entry.readings.is_empty() {
+        return;
+    }
+    let mut word_types: Vec<WordType> = vec![];
+    let mut translations: HashMap<WordType, Vec<&str>> = HashMap::new();
+    let mut usually_kana: HashMap<WordType, bool> = HashMap::new();
+    // part of speech carries over to the following senses until it is redefined
+    let mut pos: &[String] = &[];
+    for sense in entry.senses.iter() {
+        if !sense.pos.is_empty() {
+            pos = &sense.pos;
+        }
+        for word_type in pos.iter().filter_map(|tag| WordType::from_edict_tag(tag)) {
+            if !word_types.contains(&word_type) {
+                word_types.push(word_type);
+            }
+            let glosses = translations.entry(word_type).or_default();
+            glosses.extend(sense.glosses.iter().map(|gloss| gloss.as_str()));
+            *usually_kana.entry(word_type).or_insert(true) &= sense.usually_kana;
+        }
+    }
+    let keys: Vec<&str> = entry
+        .kanji
+        .iter()
+        .chain(entry.readings.iter())
+        .map(|key| key.as_str())
+        .collect();
+    for word_type in word_types {
+        let dictionary_form = if entry.kanji.is_empty() || usually_kana[&word_type] {
+            &entry.readings[0]
+        } else {
+            &entry.kanji[0]
+        };
+        dictionary.insert(
+            WordEntity {
+                dictionary_form: dictionary_form.clone(),
+                reading: entry.readings[0].clone(),
+                translation: translations[&word_type].join("; "),
+                word_type,
+            },
+            &keys,
+        );
+    }
+}
+
+// every element on the line with its attributes and text content
+fn parse_elements(line: &str) -> Vec<(&str, &str, &str)> {
+    let mut elements = vec![];
+    let mut rest = line;
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start + 1..];
+        let end = match rest.find('>') {
+            Some(end) => end,
+            None => break,
+        };
+        let header = &rest[..end];
+        let tag = header.split_whitespace().next().unwrap_or("");
+        let attributes = header[tag.len()..].trim();
+        rest = &rest[end + 1..];
+        let closing = format!("</{}>", tag);
+        match rest.find(&closing) {
+            Some(close) if !tag.starts_with('/') => {
+                elements.push((tag, attributes, &rest[..close]));
+                rest = &rest[close + closing.len()..];
+            }
+            _ => elements.push((tag, attributes, "")),
+        }
+    }
+    elements
+}
+
+// <!ENTITY v5k-s "Godan verb - Iku/Yuku special class">
+fn parse_entity_declaration(line: &str) -> Option<(String, String)> {
+    let declaration = line.trim_start_matches("<!ENTITY").trim();
+    let name_end = declaration.find(char::is_whitespace)?;
+    let description = declaration[name_end..].trim().trim_end_matches('>');
+    Some((
+        String::from(&declaration[..name_end]),
+        String::from(description.trim_matches('"')),
+    ))
+}
+
+// glosses without a language are English
+fn is_english(attributes: &str) -> bool {
+    !attributes.contains("xml:lang=") || attributes.contains("xml:lang=\"eng\"")
+}
+
+// &v5k-s; -> v5k-s
+fn entity_name(text: &str) -> Option<&str> {
+    if text.starts_with('&') && text.ends_with(';') {
+        return Some(&text[1..text.len() - 1]);
+    }
+    None
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    const JMDICT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE JMdict [
+<!ENTITY adj-na "adjectival nouns or quasi-adjectives (keiyodoshi)">
+<!ENTITY n "noun (common) (futsuumeishi)">
+<!ENTITY uk "word usually written using kana alone">
+<!ENTITY v5k-s "Godan verb - Iku/Yuku special class">
+]>
+<JMdict>
+<entry>
+<ent_seq>1578850</ent_seq>
+<k_ele>
+<keb>行く</keb>
+</k_ele>
+<k_ele>
+<keb>逝く</keb>
+</k_ele>
+<r_ele>
+<reb>いく</reb>
+</r_ele>
+<r_ele>
+<reb>ゆく</reb>
+</r_ele>
+<sense>
+<pos>&v5k-s;</pos>
+<gloss>to go</gloss>
+<gloss>to move (towards)</gloss>
+</sense>
+<sense>
+<gloss>to proceed</gloss>
+</sense>
+</entry>
+<entry>
+<ent_seq>1584470</ent_seq>
+<k_ele>
+<keb>綺麗</keb>
+</k_ele>
+<r_ele>
+<reb>きれい</reb>
+</r_ele>
+<sense>
+<pos>adjectival nouns or quasi-adjectives (keiyodoshi)</pos>
+<misc>&uk;</misc>
+<gloss>pretty</gloss>
+<gloss xml:lang="dut">mooi</gloss>
+</sense>
+</entry>
+<entry>
+<ent_seq>1000000</ent_seq>
+<r_ele>
+<reb>ほん</reb>
+</r_ele>
+<sense>
+<pos>&n;</pos>
+<gloss>book</gloss>
+</sense>
+</entry>
+</JMdict>
+"#;
+
+    #[test]
+    fn read_test() {
+        let dictionary = read(JMDICT.as_bytes()).unwrap();
+        assert_eq!(dictionary.len(), 2);
+        for key in ["行く", "逝く", "いく", "ゆく"].iter() {
+            let words = dictionary.lookup(key);
+            assert_eq!(words.len(), 1);
+            assert_eq!(words[0].dictionary_form, "行く");
+            assert_eq!(words[0].word_type, WordType::VerbGodanKS);
+            assert_eq!(words[0].translation, "to go; to move (towards); to proceed");
+        }
+        let words = dictionary.lookup("綺麗");
+        assert_eq!(words[0].dictionary_form, "きれい");
+        assert_eq!(words[0].word_type, WordType::AdjectiveNa);
+        assert!(dictionary.lookup("ほん").is_empty());
+    }
+
+    #[test]
+    fn load_gzip_test() {
+        let path = std::env::temp_dir().join("katsuyou_jmdict_test.gz");
+        let mut encoder = GzEncoder::new(File::create(&path).unwrap(), Compression::default());
+        encoder.write_all(JMDICT.as_bytes()).unwrap();
+        encoder.finish().unwrap();
+        let dictionary = load(&path).unwrap();
+        assert_eq!(dictionary.lookup("ゆく").len(), 1);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
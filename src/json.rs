@@ -0,0 +1,97 @@
+use super::form::Form;
+use super::word_entity::{Conjugation, ConjugationError, WordEntity};
+
+// the word and every form keyed by the form name on a single line,
+// forms that do not exist for the word type are null
+pub fn word_json(word: &WordEntity) -> Result<String, ConjugationError> {
+    let mut forms: Vec<String> = vec![];
+    for form in Form::all() {
+        let conjugation = match word.conjugation(form)? {
+            Some(conjugation) => conjugation_json(&conjugation),
+            None => String::from("null"),
+        };
+        forms.push(format!("{}:{}", string(form.name()), conjugation));
+    }
+    Ok(format!(
+        "{{\"dictionary_form\":{},\"reading\":{},\"translation\":{},\"word_type\":{},\"forms\":{{{}}}}}",
+        string(&word.dictionary_form),
+        string(&word.reading),
+        string(&word.translation),
+        string(word.word_type.name()),
+        forms.join(",")
+    ))
+}
+
+// a json array with a word on every line
+pub fn words_json(words: &[String]) -> String {
+    if words.is_empty() {
+        return String::from("[]");
+    }
+    format!("[\n{}\n]", words.join(",\n"))
+}
+
+fn conjugation_json(conjugation: &Conjugation) -> String {
+    format!(
+        "{{\"kanji\":{},\"kana\":{},\"romaji\":{}}}",
+        string(&conjugation.kanji),
+        string(&conjugation.kana),
+        string(&conjugation.romaji)
+    )
+}
+
+fn string(text: &str) -> String {
+    let mut json = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::word_entity::WordType;
+    use super::*;
+
+    #[test]
+    fn word_json_test() {
+        let word = WordEntity {
+            dictionary_form: String::from("読む"),
+            reading: String::from("よむ"),
+            translation: String::from("to read"),
+            word_type: WordType::VerbGodanM,
+        };
+        let json = word_json(&word).unwrap();
+        assert!(json.starts_with(
+            "{\"dictionary_form\":\"読む\",\"reading\":\"よむ\",\"translation\":\"to read\",\"word_type\":\"godan-m\",\"forms\":{"
+        ));
+        assert!(json.contains(
+            "\"formal_perfective_form\":{\"kanji\":\"読みました\",\"kana\":\"よみました\",\"romaji\":\"yomimashita\"}"
+        ));
+        let modern = Form::all()
+            .filter(|form| !form.is_classical() && *form != Form::Adverbial)
+            .count();
+        assert_eq!(json.matches("\"kanji\"").count(), modern);
+        assert!(json.contains("\"classical_attributive_form\":null"));
+        let word = WordEntity {
+            dictionary_form: String::from("高い"),
+            reading: String::from("たかい"),
+            translation: String::from("\"tall\"\n"),
+            word_type: WordType::AdjectiveI,
+        };
+        let json = word_json(&word).unwrap();
+        assert!(json.contains("\"translation\":\"\\\"tall\\\"\\n\""));
+        assert!(json.contains("\"informal_imperative_form\":null"));
+        assert_eq!(words_json(&[]), "[]");
+        let words = vec![json];
+        assert_eq!(words_json(&words), format!("[\n{}\n]", words[0]));
+    }
+}
@@ -0,0 +1,290 @@
+use super::word_entity::WordType;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Keigo {
+    // sonkeigo, raising the one doing it
+    Honorific,
+    // kenjougo, lowering the speaker
+    Humble,
+}
+
+// verbs replaced by another verb in keigo: the verb, its reading and type,
+// then the keigo verb, its reading and type
+const SUPPLETIVE_VERBS: &[(&str, &str, WordType, Keigo, &str, &str, WordType)] = &[
+    (
+        "行く",
+        "いく",
+        WordType::VerbGodanKS,
+        Keigo::Honorific,
+        "いらっしゃる",
+        "いらっしゃる",
+        WordType::VerbGodanARU,
+    ),
+    (
+        "行く",
+        "ゆく",
+        WordType::VerbGodanKS,
+        Keigo::Honorific,
+        "いらっしゃる",
+        "いらっしゃる",
+        WordType::VerbGodanARU,
+    ),
+    (
+        "来る",
+        "くる",
+        WordType::VerbKuru,
+        Keigo::Honorific,
+        "いらっしゃる",
+        "いらっしゃる",
+        WordType::VerbGodanARU,
+    ),
+    (
+        "居る",
+        "いる",
+        WordType::VerbIchidan,
+        Keigo::Honorific,
+        "いらっしゃる",
+        "いらっしゃる",
+        WordType::VerbGodanARU,
+    ),
+    (
+        "食べる",
+        "たべる",
+        WordType::VerbIchidan,
+        Keigo::Honorific,
+        "召し上がる",
+        "めしあがる",
+        WordType::VerbGodanR,
+    ),
+    (
+        "飲む",
+        "のむ",
+        WordType::VerbGodanM,
+        Keigo::Honorific,
+        "召し上がる",
+        "めしあがる",
+        WordType::VerbGodanR,
+    ),
+    (
+        "言う",
+        "いう",
+        WordType::VerbGodanU,
+        Keigo::Honorific,
+        "おっしゃる",
+        "おっしゃる",
+        WordType::VerbGodanARU,
+    ),
+    (
+        "見る",
+        "みる",
+        WordType::VerbIchidan,
+        Keigo::Honorific,
+        "ご覧になる",
+        "ごらんになる",
+        WordType::VerbGodanR,
+    ),
+    (
+        "呉れる",
+        "くれる",
+        WordType::VerbIchidan,
+        Keigo::Honorific,
+        "下さる",
+        "くださる",
+        WordType::VerbGodanARU,
+    ),
+    (
+        "寝る",
+        "ねる",
+        WordType::VerbIchidan,
+        Keigo::Honorific,
+        "お休みになる",
+        "おやすみになる",
+        WordType::VerbGodanR,
+    ),
+    (
+        "着る",
+        "きる",
+        WordType::VerbIchidan,
+        Keigo::Honorific,
+        "お召しになる",
+        "おめしになる",
+        WordType::VerbGodanR,
+    ),
+    (
+        "行く",
+        "いく",
+        WordType::VerbGodanKS,
+        Keigo::Humble,
+        "参る",
+        "まいる",
+        WordType::VerbGodanR,
+    ),
+    (
+        "行く",
+        "ゆく",
+        WordType::VerbGodanKS,
+        Keigo::Humble,
+        "参る",
+        "まいる",
+        WordType::VerbGodanR,
+    ),
+    (
+        "来る",
+        "くる",
+        WordType::VerbKuru,
+        Keigo::Humble,
+        "参る",
+        "まいる",
+        WordType::VerbGodanR,
+    ),
+    (
+        "居る",
+        "いる",
+        WordType::VerbIchidan,
+        Keigo::Humble,
+        "居る",
+        "おる",
+        WordType::VerbGodanR,
+    ),
+    (
+        "食べる",
+        "たべる",
+        WordType::VerbIchidan,
+        Keigo::Humble,
+        "いただく",
+        "いただく",
+        WordType::VerbGodanK,
+    ),
+    (
+        "飲む",
+        "のむ",
+        WordType::VerbGodanM,
+        Keigo::Humble,
+        "いただく",
+        "いただく",
+        WordType::VerbGodanK,
+    ),
+    (
+        "貰う",
+        "もらう",
+        WordType::VerbGodanU,
+        Keigo::Humble,
+        "いただく",
+        "いただく",
+        WordType::VerbGodanK,
+    ),
+    (
+        "言う",
+        "いう",
+        WordType::VerbGodanU,
+        Keigo::Humble,
+        "申す",
+        "もうす",
+        WordType::VerbGodanS,
+    ),
+    (
+        "見る",
+        "みる",
+        WordType::VerbIchidan,
+        Keigo::Humble,
+        "拝見する",
+        "はいけんする",
+        WordType::VerbSuru,
+    ),
+    (
+        "聞く",
+        "きく",
+        WordType::VerbGodanK,
+        Keigo::Humble,
+        "伺う",
+        "うかがう",
+        WordType::VerbGodanU,
+    ),
+    (
+        "会う",
+        "あう",
+        WordType::VerbGodanU,
+        Keigo::Humble,
+        "お目に掛かる",
+        "おめにかかる",
+        WordType::VerbGodanR,
+    ),
+    (
+        "知る",
+        "しる",
+        WordType::VerbGodanR,
+        Keigo::Humble,
+        "存じる",
+        "ぞんじる",
+        WordType::VerbIchidan,
+    ),
+    (
+        "上げる",
+        "あげる",
+        WordType::VerbIchidan,
+        Keigo::Humble,
+        "差し上げる",
+        "さしあげる",
+        WordType::VerbIchidan,
+    ),
+];
+
+// the verb used instead of the word in keigo with its type, written in kana when the word is,
+// e.g. めしあがる for たべる, homographs of another type are not replaced
+pub fn suppletive(word: &str, word_type: WordType, keigo: Keigo) -> Option<(String, WordType)> {
+    SUPPLETIVE_VERBS
+        .iter()
+        .filter(|entry| entry.2 == word_type && entry.3 == keigo)
+        .find_map(
+            |(dictionary_form, reading, _, _, keigo_form, keigo_reading, keigo_type)| {
+                if word == *dictionary_form {
+                    return Some((String::from(*keigo_form), *keigo_type));
+                }
+                if word == *reading {
+                    return Some((String::from(*keigo_reading), *keigo_type));
+                }
+                None
+            },
+        )
+}
+
+// the reading of a verb used in keigo, e.g. まいる for 参る
+pub fn reading(keigo_form: &str) -> Option<String> {
+    SUPPLETIVE_VERBS
+        .iter()
+        .find(|entry| entry.4 == keigo_form)
+        .map(|entry| String::from(entry.5))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suppletive_test() {
+        assert_eq!(
+            suppletive("食べる", WordType::VerbIchidan, Keigo::Honorific),
+            Some((String::from("召し上がる"), WordType::VerbGodanR))
+        );
+        assert_eq!(
+            suppletive("いう", WordType::VerbGodanU, Keigo::Humble),
+            Some((String::from("もうす"), WordType::VerbGodanS))
+        );
+        // 着る is worn, 切る is cut
+        assert!(suppletive("着る", WordType::VerbIchidan, Keigo::Honorific).is_some());
+        assert_eq!(
+            suppletive("切る", WordType::VerbGodanR, Keigo::Honorific),
+            None
+        );
+        assert_eq!(
+            suppletive("書く", WordType::VerbGodanK, Keigo::Humble),
+            None
+        );
+        assert_eq!(
+            suppletive("ゆく", WordType::VerbGodanKS, Keigo::Humble),
+            Some((String::from("まいる"), WordType::VerbGodanR))
+        );
+        assert_eq!(reading("参る"), Some(String::from("まいる")));
+        assert_eq!(reading("書く"), None);
+    }
+}
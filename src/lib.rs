@@ -2,33 +2,279 @@
 extern crate clap;
 extern crate console;
 
-#[cfg(test)]
 #[macro_use]
 extern crate lazy_static;
 extern crate test;
 
+pub mod batch;
 mod cli;
 mod constant;
+mod csv;
+pub mod deconjugator;
+pub mod derivation;
+pub mod dictionary;
+pub mod exceptions;
+pub mod form;
+pub mod inference;
+pub mod jmdict;
+mod json;
+pub mod keigo;
 mod printer;
-mod searcher;
-mod word_entity;
+pub mod quiz;
+pub mod romaji;
+pub mod searcher;
+pub mod word_entity;
 
-use self::searcher::{search_word, Result};
-use self::word_entity::{WordEntity, WordType};
+use self::batch::{parse_line, Entry};
+use self::dictionary::Dictionary;
+use self::form::Form;
+use self::quiz::Quiz;
+use self::searcher::{search_derivations_in, search_word_in, Result};
+use self::word_entity::{ConjugationError, WordEntity, WordType};
+use clap::ArgMatches;
+use std::fs;
+use std::io::{self, Read};
+use std::iter;
+use std::path::Path;
+use std::process;
+use std::result::Result as StdResult;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 pub fn run() {
     let args = cli::app_arguments();
-    let word = args.value_of(cli::WORD).unwrap();
-    match search_word(word) {
-        Result::None => {
-            let sample = WordEntity {
-                dictionary_form: String::from("食べる"),
-                translation: String::from("to eat"),
-                word_type: WordType::VerbIchidan,
+    let dictionary = load_dictionary(&args);
+    if let Some(quiz_args) = args.subcommand_matches(cli::QUIZ) {
+        run_quiz(&dictionary, quiz_args);
+        return;
+    }
+    let scripts: Vec<&str> = args.values_of(cli::SCRIPT).unwrap().collect();
+    let scripts = printer::Scripts {
+        kanji: scripts.contains(&cli::KANJI),
+        kana: scripts.contains(&cli::KANA),
+        romaji: scripts.contains(&cli::ROMAJI),
+    };
+    let format = args.value_of(cli::FORMAT).unwrap();
+    if args.is_present(cli::EXPLAIN) && format != cli::TABLE {
+        eprintln!("--explain can only be used with the table format");
+        process::exit(1);
+    }
+    // the location of every word in the batch file is shown in front of its errors
+    let entries: Vec<(String, StdResult<Option<Entry>, ConjugationError>)> =
+        match args.value_of(cli::BATCH) {
+            Some(path) => read_batch(path)
+                .lines()
+                .enumerate()
+                .map(|(number, line)| (format!("line {}: ", number + 1), parse_line(line)))
+                .collect(),
+            None => vec![(
+                String::new(),
+                Ok(Some(Entry {
+                    word: String::from(args.value_of(cli::WORD).unwrap()),
+                    word_type: None,
+                })),
+            )],
+        };
+    let table = csv::Table {
+        separator: if format == cli::TSV { '\t' } else { ',' },
+        long: args.is_present(cli::LONG),
+    };
+    if format == cli::CSV || format == cli::TSV {
+        println!("{}", table.header());
+    }
+    let mut failed = false;
+    let mut objects: Vec<String> = vec![];
+    for (location, entry) in entries {
+        let mut entry = match entry {
+            Ok(Some(entry)) => entry,
+            Ok(None) => continue,
+            Err(error) => {
+                eprintln!("{}{}", location, error);
+                failed = true;
+                continue;
+            }
+        };
+        if romaji::is_romaji(&entry.word) {
+            entry.word = romaji::to_kana(&entry.word);
+        }
+        if args.is_present(cli::EXPLAIN) {
+            for derivation in search_derivations_in(&dictionary, &entry.word) {
+                printer::print_derivation(&derivation, &scripts);
+            }
+        }
+        let results = match find_words(&dictionary, &entry, &location) {
+            Some(results) => results,
+            None => {
+                eprintln!("{}No verb or adjective found for: {}", location, entry.word);
+                failed = true;
+                continue;
+            }
+        };
+        for result in results.iter() {
+            let printed = match format {
+                cli::JSON => json::word_json(result).map(|object| objects.push(object)),
+                cli::JSONL => json::word_json(result).map(|object| println!("{}", object)),
+                cli::CSV | cli::TSV => table.rows(result, &scripts).map(|rows| {
+                    for row in rows {
+                        println!("{}", row);
+                    }
+                }),
+                _ => printer::print_word(result, &scripts),
             };
-            printer::print_word(&sample)
+            if let Err(error) = printed {
+                eprintln!(
+                    "{}Cannot conjugate {}: {}",
+                    location, result.dictionary_form, error
+                );
+                failed = true;
+            }
+        }
+    }
+    if format == cli::JSON {
+        println!("{}", json::words_json(&objects));
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn run_quiz(dictionary: &Dictionary, args: &ArgMatches) {
+    let word_types: StdResult<Vec<WordType>, ConjugationError> = args
+        .values_of(cli::TYPES)
+        .map(|tags| tags.map(str::parse).collect())
+        .unwrap_or_else(|| Ok(vec![]));
+    let word_types = word_types.unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(1);
+    });
+    let mut forms: Vec<Form> = vec![];
+    for name in args.values_of(cli::FORMS).into_iter().flatten() {
+        match Form::from_name(name) {
+            Some(form) => forms.push(form),
+            None => {
+                eprintln!("Unknown form: {}", name);
+                process::exit(1);
+            }
+        }
+    }
+    let questions = number(args, cli::QUESTIONS).unwrap_or(0);
+    let seed = number(args, cli::SEED).unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_nanos() as u64)
+            .unwrap_or_default()
+    });
+    let mut quiz = Quiz::new(dictionary, &word_types, &forms, seed);
+    if quiz.question().is_none() {
+        eprintln!("No word has any of the forms to ask");
+        process::exit(1);
+    }
+    let stdin = io::stdin();
+    if let Err(error) = quiz.run(questions as usize, &mut stdin.lock(), &mut io::stdout()) {
+        eprintln!("Cannot run the quiz: {}", error);
+        process::exit(1);
+    }
+}
+
+fn number(args: &ArgMatches, name: &str) -> Option<u64> {
+    let value = args.value_of(name)?;
+    match value.parse() {
+        Ok(number) => Some(number),
+        Err(_) => {
+            eprintln!("--{} should be a number: {}", name, value);
+            process::exit(1);
+        }
+    }
+}
+
+// the dictionary entries of the word type when it is given, a word missing from
+// the dictionary is conjugated by the given word type or the one it most likely has,
+// none when its type cannot be guessed well enough
+fn find_words(dictionary: &Dictionary, entry: &Entry, location: &str) -> Option<Vec<WordEntity>> {
+    let results = match search_word_in(dictionary, &entry.word) {
+        Result::None => vec![],
+        Result::Single(result) => vec![result],
+        Result::Many(results) => results,
+    };
+    let results: Vec<WordEntity> = results
+        .into_iter()
+        .filter(|result| {
+            entry
+                .word_type
+                .is_none_or(|word_type| result.word_type == word_type)
+        })
+        .collect();
+    if !results.is_empty() {
+        return Some(results);
+    }
+    let word_type = match entry.word_type {
+        Some(word_type) => word_type,
+        None => {
+            let inference = inference::infer_word_type(&entry.word, None)?;
+            if inference.confidence < inference::MIN_CONFIDENCE {
+                let alternatives = inference
+                    .alternatives
+                    .iter()
+                    .map(|(word_type, _)| *word_type);
+                let word_types: Vec<&str> = iter::once(inference.word_type)
+                    .chain(alternatives)
+                    .map(WordType::name)
+                    .collect();
+                eprintln!(
+                    "{}{} is not in the dictionary, its word type could be {}",
+                    location,
+                    entry.word,
+                    word_types.join(" or ")
+                );
+                return None;
+            }
+            eprintln!(
+                "{}{} is not in the dictionary, conjugated as a {} word ({:.0}% sure)",
+                location,
+                entry.word,
+                inference.word_type.name(),
+                inference.confidence * 100.0
+            );
+            inference.word_type
+        }
+    };
+    Some(vec![WordEntity {
+        dictionary_form: entry.word.clone(),
+        reading: String::new(),
+        translation: String::new(),
+        word_type,
+    }])
+}
+
+// - is the standard input
+fn read_batch(path: &str) -> String {
+    let mut content = String::new();
+    let read = if path == "-" {
+        io::stdin().read_to_string(&mut content).map(|_| content)
+    } else {
+        fs::read_to_string(path)
+    };
+    match read {
+        Ok(content) => content,
+        Err(error) => {
+            eprintln!("Cannot read {}: {}", path, error);
+            process::exit(1);
+        }
+    }
+}
+
+fn load_dictionary(args: &ArgMatches) -> Dictionary {
+    let (path, loaded) = if let Some(path) = args.value_of(cli::JMDICT) {
+        (path, jmdict::load(Path::new(path)))
+    } else if let Some(path) = args.value_of(cli::DICTIONARY) {
+        (path, Dictionary::load(Path::new(path)))
+    } else {
+        return Dictionary::bundled();
+    };
+    match loaded {
+        Ok(dictionary) => dictionary,
+        Err(error) => {
+            eprintln!("Cannot load dictionary {}: {}", path, error);
+            process::exit(1);
         }
-        Result::Single(_result) => {}
-        Result::Many(_results) => {}
     }
 }
@@ -1,10 +1,374 @@
-use super::word_entity::WordEntity;
-use console::style;
+use super::derivation::Derivation;
+use super::form::Polarity::{self, *};
+use super::form::Politeness::{self, *};
+use super::form::Tense::*;
+use super::form::{Base, Form};
+use super::word_entity::{Conjugation, ConjugationError, WordEntity};
+use console::{measure_text_width, pad_str, style, Alignment};
 
-pub fn print_word(word: &WordEntity) {
+// which of the kanji, kana and romaji forms are printed
+pub struct Scripts {
+    pub kanji: bool,
+    pub kana: bool,
+    pub romaji: bool,
+}
+
+const COLUMNS: [(&str, Polarity, Politeness); 4] = [
+    ("Plain affirmative", Affirmative, Plain),
+    ("Plain negative", Negative, Plain),
+    ("Polite affirmative", Affirmative, Polite),
+    ("Polite negative", Negative, Polite),
+];
+
+// the form of a row in a column, some forms have no counterpart
+type Cell = fn(Polarity, Politeness) -> Option<Form>;
+
+const ROWS: &[(&str, Cell)] = &[
+    ("Imperfective", |polarity, politeness| {
+        Some(Form::Indicative(NonPast, polarity, politeness))
+    }),
+    ("Perfective", |polarity, politeness| {
+        Some(Form::Indicative(Past, polarity, politeness))
+    }),
+    ("Imperfective variant", |polarity, politeness| {
+        match (polarity, politeness) {
+            (Negative, Polite) => Some(Form::NaiDesu(NonPast)),
+            _ => None,
+        }
+    }),
+    ("Perfective variant", |polarity, politeness| {
+        match (polarity, politeness) {
+            (Negative, Polite) => Some(Form::NaiDesu(Past)),
+            _ => None,
+        }
+    }),
+    ("Potential", |polarity, politeness| {
+        Some(Form::Potential(NonPast, polarity, politeness))
+    }),
+    ("Perfective potential", |polarity, politeness| {
+        Some(Form::Potential(Past, polarity, politeness))
+    }),
+    ("Imperative", |polarity, politeness| {
+        Some(Form::Imperative(polarity, politeness))
+    }),
+    ("Written imperative", |polarity, politeness| {
+        match (polarity, politeness) {
+            (Affirmative, Plain) => Some(Form::WrittenImperative),
+            _ => None,
+        }
+    }),
+    ("Volitional", |polarity, politeness| {
+        Some(Form::Volitional(polarity, politeness))
+    }),
+    ("Short volitional", |polarity, politeness| {
+        match (polarity, politeness) {
+            (Negative, Plain) => Some(Form::ShortNegativeVolitional),
+            _ => None,
+        }
+    }),
+    ("Te form", |polarity, politeness| match politeness {
+        Plain => Some(Form::Te(polarity)),
+        Polite => None,
+    }),
+    ("Nakute form", |polarity, politeness| {
+        match (polarity, politeness) {
+            (Negative, Plain) => Some(Form::Nakute),
+            _ => None,
+        }
+    }),
+    ("Adverbial", |polarity, politeness| {
+        match (polarity, politeness) {
+            (Affirmative, Plain) => Some(Form::Adverbial),
+            _ => None,
+        }
+    }),
+    ("Progressive", |polarity, politeness| {
+        Some(Form::Progressive(NonPast, polarity, politeness))
+    }),
+    ("Perfective progressive", |polarity, politeness| {
+        Some(Form::Progressive(Past, polarity, politeness))
+    }),
+    ("Provisional", |polarity, politeness| match politeness {
+        Plain => Some(Form::Provisional(polarity)),
+        Polite => None,
+    }),
+    ("Conditional", |polarity, politeness| match politeness {
+        Plain => Some(Form::Conditional(polarity)),
+        Polite => None,
+    }),
+    ("Hypothetical", |polarity, politeness| match politeness {
+        Plain => Some(Form::Hypothetical(polarity)),
+        Polite => None,
+    }),
+];
+
+// the bases of classical words, modern words have none of them
+const CLASSICAL_ROWS: &[(&str, Cell)] = &[
+    ("Irrealis", |polarity, politeness| match politeness {
+        Plain => match polarity {
+            Affirmative => Some(Form::Classical(Base::Irrealis)),
+            Negative => Some(Form::ClassicalNegative),
+        },
+        Polite => None,
+    }),
+    ("Continuative", |polarity, politeness| {
+        classical_base(polarity, politeness, Base::Continuative)
+    }),
+    ("Terminal", |polarity, politeness| {
+        classical_base(polarity, politeness, Base::Terminal)
+    }),
+    ("Attributive", |polarity, politeness| {
+        classical_base(polarity, politeness, Base::Attributive)
+    }),
+    ("Realis", |polarity, politeness| {
+        classical_base(polarity, politeness, Base::Realis)
+    }),
+    ("Classical imperative", |polarity, politeness| {
+        classical_base(polarity, politeness, Base::Imperative)
+    }),
+    ("Classical volitional", |polarity, politeness| {
+        match (polarity, politeness) {
+            (Affirmative, Plain) => Some(Form::ClassicalVolitional),
+            _ => None,
+        }
+    }),
+];
+
+const COLUMN_SEPARATOR: &str = "  ";
+const STEP_SEPARATOR: &str = " → ";
+const SCRIPT_SEPARATOR: &str = " / ";
+
+pub fn print_word(word: &WordEntity, scripts: &Scripts) -> Result<(), ConjugationError> {
+    let lines = table(word, scripts)?;
+    let dictionary_form = Conjugation {
+        kanji: word.dictionary_form.clone(),
+        kana: if word.reading.is_empty() {
+            word.dictionary_form.clone()
+        } else {
+            word.reading.clone()
+        },
+        romaji: word
+            .conjugation(Form::DICTIONARY)?
+            .map(|conjugation| conjugation.romaji)
+            .unwrap_or_default(),
+    };
+    // words missing from the dictionary have no translation
+    let translation = if word.translation.is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", word.translation)
+    };
     println!(
-        "Conjugation for word: {}
+        "Conjugation for word: {}{}
 is:",
-        style(&word.dictionary_form).bold()
-    )
+        style(rendered(&dictionary_form, scripts)).bold(),
+        translation
+    );
+    for line in lines {
+        println!("{}", line.trim_end());
+    }
+    Ok(())
+}
+
+pub fn print_derivation(derivation: &Derivation, scripts: &Scripts) {
+    println!(
+        "Derivation for word: {}",
+        style(&derivation.word.dictionary_form).bold()
+    );
+    for line in derivation_lines(derivation, scripts) {
+        println!("{}", line);
+    }
+}
+
+// the whole derivation in every selected script,
+// the kana line is left out when it is the same as the kanji line
+fn derivation_lines(derivation: &Derivation, scripts: &Scripts) -> Vec<String> {
+    let mut script_forms: Vec<fn(&Conjugation) -> &str> = vec![];
+    if scripts.kanji {
+        script_forms.push(|conjugation| &conjugation.kanji);
+    }
+    if scripts.kana {
+        script_forms.push(|conjugation| &conjugation.kana);
+    }
+    if scripts.romaji {
+        script_forms.push(|conjugation| &conjugation.romaji);
+    }
+    let mut lines: Vec<String> = vec![];
+    for script_form in script_forms {
+        let mut steps = vec![format!(
+            "{} ({})",
+            script_form(&derivation.dictionary_form),
+            derivation.word.word_type.name()
+        )];
+        steps.extend(derivation.steps.iter().map(|step| {
+            format!(
+                "{} {}",
+                step.transformation.name(),
+                script_form(&step.conjugation)
+            )
+        }));
+        let line = steps.join(STEP_SEPARATOR);
+        if !lines.contains(&line) {
+            lines.push(line);
+        }
+    }
+    lines
+}
+
+// a line of a cell for every script, the same line of every cell is aligned
+fn table(word: &WordEntity, scripts: &Scripts) -> Result<Vec<String>, ConjugationError> {
+    let mut header = vec![String::new()];
+    header.extend(COLUMNS.iter().map(|(column, _, _)| String::from(*column)));
+    let mut lines = vec![header];
+    for (label, cell) in ROWS.iter().chain(CLASSICAL_ROWS.iter()) {
+        let mut cells: Vec<Vec<Option<String>>> = vec![];
+        for (_, polarity, politeness) in COLUMNS.iter() {
+            let conjugation = match cell(*polarity, *politeness) {
+                Some(form) => word.conjugation(form)?,
+                None => None,
+            };
+            cells.push(match conjugation {
+                Some(conjugation) => render(&conjugation, scripts),
+                None => vec![None; script_count(scripts)],
+            });
+        }
+        let mut label = Some(String::from(*label));
+        for script in 0..cells[0].len() {
+            if cells.iter().all(|cell| cell[script].is_none()) {
+                continue;
+            }
+            let mut line = vec![label.take().unwrap_or_default()];
+            line.extend(
+                cells
+                    .iter()
+                    .map(|cell| cell[script].clone().unwrap_or_default()),
+            );
+            lines.push(line);
+        }
+    }
+    let mut widths = [0; COLUMNS.len() + 1];
+    for line in lines.iter() {
+        for (width, cell) in widths.iter_mut().zip(line.iter()) {
+            *width = (*width).max(measure_text_width(cell));
+        }
+    }
+    Ok(lines
+        .iter()
+        .enumerate()
+        .map(|(number, line)| {
+            line.iter()
+                .zip(widths.iter())
+                .enumerate()
+                .map(|(column, (cell, width))| {
+                    let cell = pad_str(cell, *width, Alignment::Left, None);
+                    if number == 0 || column == 0 {
+                        return style(cell).bold().to_string();
+                    }
+                    cell.to_string()
+                })
+                .collect::<Vec<String>>()
+                .join(COLUMN_SEPARATOR)
+        })
+        .collect())
+}
+
+// a base only has the plain affirmative column
+fn classical_base(polarity: Polarity, politeness: Politeness, base: Base) -> Option<Form> {
+    match (polarity, politeness) {
+        (Affirmative, Plain) => Some(Form::Classical(base)),
+        _ => None,
+    }
+}
+
+// the form in every selected script on one line
+pub fn rendered(conjugation: &Conjugation, scripts: &Scripts) -> String {
+    let forms: Vec<String> = render(conjugation, scripts).into_iter().flatten().collect();
+    forms.join(SCRIPT_SEPARATOR)
+}
+
+fn script_count(scripts: &Scripts) -> usize {
+    [scripts.kanji, scripts.kana, scripts.romaji]
+        .iter()
+        .filter(|&&script| script)
+        .count()
+}
+
+// the form in every selected script, kana is left out when it is the same as the kanji
+fn render(conjugation: &Conjugation, scripts: &Scripts) -> Vec<Option<String>> {
+    let mut forms = vec![];
+    if scripts.kanji {
+        forms.push(Some(conjugation.kanji.clone()));
+    }
+    if scripts.kana {
+        if scripts.kanji && conjugation.kana == conjugation.kanji {
+            forms.push(None);
+        } else {
+            forms.push(Some(conjugation.kana.clone()));
+        }
+    }
+    if scripts.romaji {
+        forms.push(Some(conjugation.romaji.clone()));
+    }
+    forms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::derivation::Transformation;
+    use super::super::word_entity::WordType;
+    use super::*;
+    use console::strip_ansi_codes;
+
+    #[test]
+    fn derivation_lines_test() {
+        let word = WordEntity {
+            dictionary_form: String::from("読む"),
+            reading: String::from("よむ"),
+            translation: String::from("to read"),
+            word_type: WordType::VerbGodanM,
+        };
+        let transformations = [
+            Transformation::Causative,
+            Transformation::Form(Form::Indicative(Past, Negative, Plain)),
+        ];
+        let derivation = Derivation::new(&word, &transformations).unwrap().unwrap();
+        let scripts = Scripts {
+            kanji: true,
+            kana: false,
+            romaji: true,
+        };
+        assert_eq!(
+            derivation_lines(&derivation, &scripts),
+            vec![
+                "読む (godan-m) → causative 読ませる → negative past 読ませなかった",
+                "yomu (godan-m) → causative yomaseru → negative past yomasenakatta",
+            ]
+        );
+    }
+
+    #[test]
+    fn table_test() {
+        let word = WordEntity {
+            dictionary_form: String::from("有る"),
+            reading: String::from("ある"),
+            translation: String::from("to be"),
+            word_type: WordType::VerbGodanRI,
+        };
+        let scripts = Scripts {
+            kanji: true,
+            kana: true,
+            romaji: true,
+        };
+        let lines = table(&word, &scripts).unwrap();
+        // header and three lines for every row, except the nakute form and the
+        // variant rows where the kana is the same as the kanji, the adverbial
+        // row verbs do not have and the two progressive rows ある does not have
+        assert_eq!(lines.len(), 1 + ROWS.len() * 3 - 3 - 3 - 6);
+        let width = measure_text_width(&lines[0]);
+        for line in lines.iter() {
+            assert_eq!(measure_text_width(line), width);
+        }
+        assert!(strip_ansi_codes(&lines[1]).starts_with("Imperfective "));
+        assert!(lines[3].contains("aru"));
+    }
 }
@@ -0,0 +1,206 @@
+use super::dictionary::Dictionary;
+use super::form::Form;
+use super::romaji;
+use super::word_entity::{Conjugation, WordEntity, WordType};
+use std::io::{self, BufRead, Write};
+
+// a word and form are drawn again when the form does not exist for the word
+const MAX_DRAWS: usize = 100;
+
+// a form of a word to give
+#[derive(Debug, Clone, PartialEq)]
+pub struct Question {
+    pub word: WordEntity,
+    pub form: Form,
+    pub answer: Conjugation,
+}
+
+impl Question {
+    // e.g. polite negative past of 書く (かく)
+    pub fn prompt(&self) -> String {
+        if self.word.reading.is_empty() || self.word.reading == self.word.dictionary_form {
+            return format!(
+                "{} of {}",
+                self.form.description(),
+                self.word.dictionary_form
+            );
+        }
+        format!(
+            "{} of {} ({})",
+            self.form.description(),
+            self.word.dictionary_form,
+            self.word.reading
+        )
+    }
+
+    // the answer can be written with kanji, in kana or in romaji
+    pub fn is_correct(&self, answer: &str) -> bool {
+        let answer = answer.trim();
+        if answer.is_empty() {
+            return false;
+        }
+        let kana: String = answer.chars().map(romaji::to_hiragana).collect();
+        answer == self.answer.kanji
+            || kana == self.answer.kana
+            || answer.to_lowercase() == self.answer.romaji
+            || (romaji::is_romaji(answer) && romaji::to_kana(answer) == self.answer.kana)
+    }
+}
+
+// random questions on words of the dictionary, every word type and form when none are given
+pub struct Quiz {
+    words: Vec<WordEntity>,
+    forms: Vec<Form>,
+    random: Random,
+}
+
+impl Quiz {
+    pub fn new(
+        dictionary: &Dictionary,
+        word_types: &[WordType],
+        forms: &[Form],
+        seed: u64,
+    ) -> Quiz {
+        let words = dictionary
+            .iter()
+            .filter(|word| word_types.is_empty() || word_types.contains(&word.word_type))
+            .filter(|word| word.conjugation(Form::DICTIONARY).is_ok())
+            .cloned()
+            .collect();
+        let forms = if forms.is_empty() {
+            Form::all()
+                .filter(|form| *form != Form::DICTIONARY)
+                .collect()
+        } else {
+            forms.to_vec()
+        };
+        Quiz {
+            words,
+            forms,
+            random: Random::new(seed),
+        }
+    }
+
+    // None when no word has any of the forms
+    pub fn question(&mut self) -> Option<Question> {
+        if self.words.is_empty() || self.forms.is_empty() {
+            return None;
+        }
+        for _ in 0..MAX_DRAWS {
+            let word = &self.words[self.random.below(self.words.len())];
+            let form = self.forms[self.random.below(self.forms.len())];
+            if let Ok(Some(answer)) = word.conjugation(form) {
+                return Some(Question {
+                    word: word.clone(),
+                    form,
+                    answer,
+                });
+            }
+        }
+        None
+    }
+
+    // asks until every question is answered or the input ends, returns the score
+    pub fn run(
+        &mut self,
+        questions: usize,
+        input: &mut impl BufRead,
+        output: &mut impl Write,
+    ) -> io::Result<(usize, usize)> {
+        let mut score = (0, 0);
+        while score.1 < questions {
+            let question = match self.question() {
+                Some(question) => question,
+                None => break,
+            };
+            write!(output, "{}? ", question.prompt())?;
+            output.flush()?;
+            let mut answer = String::new();
+            if input.read_line(&mut answer)? == 0 {
+                writeln!(output)?;
+                break;
+            }
+            score.1 += 1;
+            if question.is_correct(&answer) {
+                score.0 += 1;
+                writeln!(output, "Correct!")?;
+            } else if question.answer.kanji == question.answer.kana {
+                writeln!(output, "Wrong, it is {}", question.answer.kanji)?;
+            } else {
+                writeln!(
+                    output,
+                    "Wrong, it is {} ({})",
+                    question.answer.kanji, question.answer.kana
+                )?;
+            }
+        }
+        writeln!(output, "Score: {}/{}", score.0, score.1)?;
+        Ok(score)
+    }
+}
+
+// xorshift, good enough to pick questions
+struct Random(u64);
+
+impl Random {
+    fn new(seed: u64) -> Random {
+        Random(seed.max(1))
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % bound as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::form::Polarity::*;
+    use super::super::form::Politeness::*;
+    use super::super::form::Tense::*;
+    use super::*;
+
+    const POLITE_NEGATIVE_PAST: Form = Form::Indicative(Past, Negative, Polite);
+
+    #[test]
+    fn question_test() {
+        let dictionary = Dictionary::bundled();
+        let mut quiz = Quiz::new(
+            &dictionary,
+            &[WordType::VerbGodanK],
+            &[POLITE_NEGATIVE_PAST],
+            7,
+        );
+        let question = quiz.question().unwrap();
+        assert_eq!(question.word.word_type, WordType::VerbGodanK);
+        assert_eq!(question.form, POLITE_NEGATIVE_PAST);
+        assert!(question.prompt().starts_with("polite negative past of "));
+        assert!(question.is_correct(&question.answer.kanji));
+        assert!(question.is_correct(&question.answer.kana));
+        assert!(question.is_correct(&question.answer.romaji.to_uppercase()));
+        assert!(!question.is_correct(&question.word.dictionary_form));
+        assert!(!question.is_correct(""));
+        let mut quiz = Quiz::new(
+            &dictionary,
+            &[WordType::AdjectiveI],
+            &[Form::Imperative(Affirmative, Plain)],
+            7,
+        );
+        assert_eq!(quiz.question(), None);
+    }
+
+    #[test]
+    fn run_test() {
+        let dictionary = Dictionary::bundled();
+        let mut quiz = Quiz::new(&dictionary, &[], &[], 42);
+        let mut input = io::Cursor::new("wrong\n");
+        let mut output: Vec<u8> = vec![];
+        let score = quiz.run(3, &mut input, &mut output).unwrap();
+        assert_eq!(score, (0, 1));
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Wrong, it is "));
+        assert!(output.ends_with("Score: 0/1\n"));
+    }
+}
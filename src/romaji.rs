@@ -0,0 +1,387 @@
+// Hepburn, Kunrei-shiki and Nihon-shiki romaji to hiragana,
+// the Hepburn romaji is listed first for every kana
+const SYLLABLES: &[(&str, &str)] = &[
+    ("a", "あ"),
+    ("i", "い"),
+    ("u", "う"),
+    ("e", "え"),
+    ("o", "お"),
+    ("ka", "か"),
+    ("ki", "き"),
+    ("ku", "く"),
+    ("ke", "け"),
+    ("ko", "こ"),
+    ("kya", "きゃ"),
+    ("kyu", "きゅ"),
+    ("kyo", "きょ"),
+    ("ga", "が"),
+    ("gi", "ぎ"),
+    ("gu", "ぐ"),
+    ("ge", "げ"),
+    ("go", "ご"),
+    ("gya", "ぎゃ"),
+    ("gyu", "ぎゅ"),
+    ("gyo", "ぎょ"),
+    ("sa", "さ"),
+    ("shi", "し"),
+    ("si", "し"),
+    ("su", "す"),
+    ("se", "せ"),
+    ("so", "そ"),
+    ("sha", "しゃ"),
+    ("shu", "しゅ"),
+    ("she", "しぇ"),
+    ("sho", "しょ"),
+    ("sya", "しゃ"),
+    ("syu", "しゅ"),
+    ("syo", "しょ"),
+    ("za", "ざ"),
+    ("ji", "じ"),
+    ("zi", "じ"),
+    ("zu", "ず"),
+    ("ze", "ぜ"),
+    ("zo", "ぞ"),
+    ("ja", "じゃ"),
+    ("ju", "じゅ"),
+    ("je", "じぇ"),
+    ("jo", "じょ"),
+    ("jya", "じゃ"),
+    ("jyu", "じゅ"),
+    ("jyo", "じょ"),
+    ("zya", "じゃ"),
+    ("zyu", "じゅ"),
+    ("zyo", "じょ"),
+    ("ta", "た"),
+    ("chi", "ち"),
+    ("ti", "ち"),
+    ("tsu", "つ"),
+    ("tu", "つ"),
+    ("te", "て"),
+    ("to", "と"),
+    ("cha", "ちゃ"),
+    ("chu", "ちゅ"),
+    ("che", "ちぇ"),
+    ("cho", "ちょ"),
+    ("tya", "ちゃ"),
+    ("tyu", "ちゅ"),
+    ("tyo", "ちょ"),
+    ("da", "だ"),
+    ("di", "ぢ"),
+    ("du", "づ"),
+    ("de", "で"),
+    ("do", "ど"),
+    ("dya", "ぢゃ"),
+    ("dyu", "ぢゅ"),
+    ("dyo", "ぢょ"),
+    ("na", "な"),
+    ("ni", "に"),
+    ("nu", "ぬ"),
+    ("ne", "ね"),
+    ("no", "の"),
+    ("nya", "にゃ"),
+    ("nyu", "にゅ"),
+    ("nyo", "にょ"),
+    ("ha", "は"),
+    ("hi", "ひ"),
+    ("fu", "ふ"),
+    ("hu", "ふ"),
+    ("he", "へ"),
+    ("ho", "ほ"),
+    ("hya", "ひゃ"),
+    ("hyu", "ひゅ"),
+    ("hyo", "ひょ"),
+    ("fa", "ふぁ"),
+    ("fi", "ふぃ"),
+    ("fe", "ふぇ"),
+    ("fo", "ふぉ"),
+    ("ba", "ば"),
+    ("bi", "び"),
+    ("bu", "ぶ"),
+    ("be", "べ"),
+    ("bo", "ぼ"),
+    ("bya", "びゃ"),
+    ("byu", "びゅ"),
+    ("byo", "びょ"),
+    ("pa", "ぱ"),
+    ("pi", "ぴ"),
+    ("pu", "ぷ"),
+    ("pe", "ぺ"),
+    ("po", "ぽ"),
+    ("pya", "ぴゃ"),
+    ("pyu", "ぴゅ"),
+    ("pyo", "ぴょ"),
+    ("ma", "ま"),
+    ("mi", "み"),
+    ("mu", "む"),
+    ("me", "め"),
+    ("mo", "も"),
+    ("mya", "みゃ"),
+    ("myu", "みゅ"),
+    ("myo", "みょ"),
+    ("ya", "や"),
+    ("yu", "ゆ"),
+    ("yo", "よ"),
+    ("ra", "ら"),
+    ("ri", "り"),
+    ("ru", "る"),
+    ("re", "れ"),
+    ("ro", "ろ"),
+    ("rya", "りゃ"),
+    ("ryu", "りゅ"),
+    ("ryo", "りょ"),
+    ("wa", "わ"),
+    ("wi", "ゐ"),
+    ("we", "ゑ"),
+    ("wo", "を"),
+    ("xa", "ぁ"),
+    ("xi", "ぃ"),
+    ("xu", "ぅ"),
+    ("xe", "ぇ"),
+    ("xo", "ぉ"),
+    ("xya", "ゃ"),
+    ("xyu", "ゅ"),
+    ("xyo", "ょ"),
+    ("xtsu", "っ"),
+    ("xtu", "っ"),
+    ("-", "ー"),
+];
+
+// long vowels written with macron (Hepburn) or circumflex (Kunrei-shiki, Nihon-shiki)
+const LONG_VOWELS: &[(char, &str)] = &[
+    ('ā', "aa"),
+    ('â', "aa"),
+    ('ī', "ii"),
+    ('î', "ii"),
+    ('ū', "uu"),
+    ('û', "uu"),
+    ('ē', "ee"),
+    ('ê', "ee"),
+    ('ō', "ou"),
+    ('ô', "ou"),
+];
+
+const SOKUON: &str = "っ";
+const N: &str = "ん";
+const VOWELS: &str = "aiueo";
+
+pub fn is_romaji(word: &str) -> bool {
+    word.chars().any(|c| c.is_ascii_alphabetic())
+        && word.chars().all(|c| {
+            c.is_ascii_alphabetic()
+                || c == '\''
+                || c == '-'
+                || LONG_VOWELS
+                    .iter()
+                    .any(|(long_vowel, _)| c.to_lowercase().any(|l| l == *long_vowel))
+        })
+}
+
+pub fn to_kana(word: &str) -> String {
+    let mut romaji = String::new();
+    for c in word.to_lowercase().chars() {
+        match LONG_VOWELS.iter().find(|(long_vowel, _)| *long_vowel == c) {
+            Some((_, vowels)) => romaji.push_str(vowels),
+            None => romaji.push(c),
+        }
+    }
+    let chars: Vec<char> = romaji.chars().collect();
+    let mut kana = String::new();
+    let mut position = 0;
+    while position < chars.len() {
+        let current = chars[position];
+        let next = chars.get(position + 1).cloned();
+        // n' or a lone n is ん, n before a vowel or y starts a syllable
+        if current == 'n' {
+            match next {
+                Some('\'') => {
+                    kana.push_str(N);
+                    position += 2;
+                    continue;
+                }
+                Some(c) if VOWELS.contains(c) || c == 'y' => {}
+                Some('n')
+                    if !chars
+                        .get(position + 2)
+                        .is_some_and(|&c| VOWELS.contains(c) || c == 'y') =>
+                {
+                    kana.push_str(N);
+                    position += 2;
+                    continue;
+                }
+                _ => {
+                    kana.push_str(N);
+                    position += 1;
+                    continue;
+                }
+            }
+        }
+        // Hepburn m before labial consonant: shimbun
+        if current == 'm' && next.is_some_and(|c| c == 'b' || c == 'm' || c == 'p') {
+            kana.push_str(N);
+            position += 1;
+            continue;
+        }
+        // doubled consonant is っ, including the Hepburn tch: matcha
+        if current.is_ascii_alphabetic()
+            && !VOWELS.contains(current)
+            && (next == Some(current) || (current == 't' && next == Some('c')))
+        {
+            kana.push_str(SOKUON);
+            position += 1;
+            continue;
+        }
+        match longest_syllable(&chars[position..]) {
+            Some((length, syllable)) => {
+                kana.push_str(syllable);
+                position += length;
+            }
+            None => {
+                kana.push(current);
+                position += 1;
+            }
+        }
+    }
+    kana
+}
+
+// Hepburn romaji of hiragana or katakana, long vowels are kept as written
+pub fn to_romaji(word: &str) -> String {
+    let chars: Vec<char> = word.chars().map(to_hiragana).collect();
+    let mut syllables: Vec<String> = vec![];
+    let mut position = 0;
+    while position < chars.len() {
+        match longest_kana(&chars[position..]) {
+            Some((length, syllable)) => {
+                syllables.push(String::from(syllable));
+                position += length;
+            }
+            None => {
+                syllables.push(chars[position].to_string());
+                position += 1;
+            }
+        }
+    }
+    let mut romaji = String::new();
+    for (index, syllable) in syllables.iter().enumerate() {
+        let next = syllables.get(index + 1).map_or("", |next| next.as_str());
+        if syllable == SOKUON {
+            // the consonant of the next syllable is doubled, ch becomes tch
+            if next.starts_with("ch") {
+                romaji.push('t');
+            } else if let Some(c) = next.chars().next().filter(|c| !VOWELS.contains(*c)) {
+                romaji.push(c);
+            }
+        } else if syllable == N {
+            romaji.push('n');
+            if next.starts_with(|c| VOWELS.contains(c) || c == 'y') {
+                romaji.push('\'');
+            }
+        } else {
+            romaji.push_str(syllable);
+        }
+    }
+    romaji
+}
+
+pub fn to_hiragana(c: char) -> char {
+    match c {
+        'ァ'..='ヶ' => std::char::from_u32(c as u32 - 0x60).unwrap_or(c),
+        _ => c,
+    }
+}
+
+// っ and ん are kept as kana, their romaji depends on the next syllable
+fn longest_kana(chars: &[char]) -> Option<(usize, &'static str)> {
+    match chars[0] {
+        'っ' => return Some((1, SOKUON)),
+        'ん' => return Some((1, N)),
+        _ => {}
+    }
+    for length in (1..=chars.len().min(2)).rev() {
+        let candidate: String = chars[..length].iter().collect();
+        if let Some((romaji, _)) = SYLLABLES.iter().find(|(_, kana)| *kana == candidate) {
+            return Some((length, romaji));
+        }
+    }
+    None
+}
+
+fn longest_syllable(chars: &[char]) -> Option<(usize, &'static str)> {
+    for length in (1..=chars.len().min(4)).rev() {
+        let candidate: String = chars[..length].iter().collect();
+        if let Some((_, syllable)) = SYLLABLES.iter().find(|(romaji, _)| *romaji == candidate) {
+            return Some((length, syllable));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_kana_test() {
+        let words = [
+            ("taberu", "たべる"),
+            ("ikimasu", "いきます"),
+            ("shinbun", "しんぶん"),
+            ("shimbun", "しんぶん"),
+            ("gakkou", "がっこう"),
+            ("matcha", "まっちゃ"),
+            ("kippu", "きっぷ"),
+            ("kon'ya", "こんや"),
+            ("konya", "こにゃ"),
+            ("konnichiha", "こんにちは"),
+            ("onna", "おんな"),
+            ("hon", "ほん"),
+            ("honn", "ほん"),
+            ("benkyō", "べんきょう"),
+            ("benkyou", "べんきょう"),
+            ("tōkyō", "とうきょう"),
+            ("obāsan", "おばあさん"),
+            ("Tsukau", "つかう"),
+            ("tukau", "つかう"),
+            ("sinzyuku", "しんじゅく"),
+            ("hanasu", "はなす"),
+            ("tiisai", "ちいさい"),
+            ("hutatu", "ふたつ"),
+            ("hanadi", "はなぢ"),
+            ("tuduku", "つづく"),
+            ("kôhî", "こうひい"),
+        ];
+        for (romaji, kana) in words.iter() {
+            assert_eq!(to_kana(romaji), *kana, "{}", romaji);
+        }
+    }
+
+    #[test]
+    fn to_romaji_test() {
+        let words = [
+            ("たべる", "taberu"),
+            ("たべなかった", "tabenakatta"),
+            ("じゅんびします", "junbishimasu"),
+            ("べんきょう", "benkyou"),
+            ("まっちゃ", "matcha"),
+            ("こんや", "kon'ya"),
+            ("いらっしゃいませ", "irasshaimase"),
+            ("しんいち", "shin'ichi"),
+            ("つかう", "tsukau"),
+            ("コーヒー", "ko-hi-"),
+            ("-", "-"),
+        ];
+        for (kana, romaji) in words.iter() {
+            assert_eq!(to_romaji(kana), *romaji, "{}", kana);
+        }
+    }
+
+    #[test]
+    fn is_romaji_test() {
+        assert!(is_romaji("taberu"));
+        assert!(is_romaji("kon'ya"));
+        assert!(is_romaji("Benkyō"));
+        assert!(!is_romaji("食べる"));
+        assert!(!is_romaji("たべる"));
+        assert!(!is_romaji("'"));
+    }
+}
@@ -1,4 +1,8 @@
-use super::word_entity::WordEntity;
+use super::deconjugator::Deconjugator;
+use super::derivation::Derivation;
+use super::dictionary::Dictionary;
+use super::exceptions::Exceptions;
+use super::word_entity::{WordEntity, WordType};
 
 pub enum Result {
     None,
@@ -7,5 +11,200 @@ pub enum Result {
 }
 
 pub fn search_word(word: &str) -> Result {
-    Result::None
+    search_word_in(&Dictionary::bundled(), word)
+}
+
+// a dictionary form is found even when the entry cannot be conjugated,
+// so the error can be reported for it
+pub fn search_word_in(dictionary: &Dictionary, word: &str) -> Result {
+    let mut results = lookup(dictionary, Exceptions::bundled(), word.trim());
+    if results.is_empty() {
+        for derivation in search_derivations_in(dictionary, word) {
+            if !results.contains(&derivation.word) {
+                results.push(derivation.word);
+            }
+        }
+    }
+    match results.len() {
+        0 => Result::None,
+        1 => Result::Single(results.remove(0)),
+        _ => Result::Many(results),
+    }
+}
+
+// a dictionary form is found as it is, conjugated words are searched by the
+// dictionary forms they can come from and kept if they conjugate back to the word,
+// entries that cannot be conjugated are left out. a word that is not a modern form
+// is also searched as the classical word a modern entry comes from, e.g. 死ぬる
+pub fn search_derivations_in(dictionary: &Dictionary, word: &str) -> Vec<Derivation> {
+    let word = word.trim();
+    let exceptions = Exceptions::bundled();
+    let words = lookup(dictionary, exceptions, word);
+    if !words.is_empty() {
+        return words
+            .iter()
+            .filter_map(|word| Derivation::new(word, &[]).ok().flatten())
+            .collect();
+    }
+    let mut derivations: Vec<Derivation> = vec![];
+    let mut classical: Vec<Derivation> = vec![];
+    for candidate in Deconjugator::bundled().deconjugate(word) {
+        for result in lookup(dictionary, exceptions, &candidate.dictionary_form) {
+            let (result, found) = if candidate
+                .word_type
+                .modern_types()
+                .contains(&result.word_type)
+            {
+                let result = WordEntity {
+                    word_type: candidate.word_type,
+                    ..result
+                };
+                (result, &mut classical)
+            } else {
+                (result, &mut derivations)
+            };
+            // auxiliary verbs are kept when they conjugate back to the word
+            if result.word_type != candidate.word_type
+                && result.word_type != WordType::AuxiliaryVerb
+            {
+                continue;
+            }
+            let derivation = match Derivation::new(&result, &candidate.transformations) {
+                Ok(Some(derivation)) => derivation,
+                _ => continue,
+            };
+            let conjugation = derivation.conjugation();
+            if (conjugation.kanji == word || conjugation.kana == word)
+                && !found.contains(&derivation)
+            {
+                found.push(derivation);
+            }
+        }
+    }
+    if derivations.is_empty() {
+        return classical;
+    }
+    derivations
+}
+
+// godan verbs that look like ichidan verbs are found without translation
+// when the dictionary does not have them
+fn lookup(dictionary: &Dictionary, exceptions: &Exceptions, word: &str) -> Vec<WordEntity> {
+    let words = dictionary.lookup(word);
+    if !words.is_empty() {
+        return words.into_iter().cloned().collect();
+    }
+    exceptions
+        .lookup(word, None)
+        .into_iter()
+        .map(|exception| exception.word_entity())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::word_entity::WordType;
+    use super::*;
+
+    #[test]
+    fn search_kanji_test() {
+        match search_word("食べる") {
+            Result::Single(word) => {
+                assert_eq!(word.dictionary_form, "食べる");
+                assert_eq!(word.translation, "to eat");
+                assert_eq!(word.word_type, WordType::VerbIchidan);
+            }
+            _ => panic!("食べる should be found once"),
+        }
+    }
+
+    #[test]
+    fn search_kana_test() {
+        match search_word("かえる") {
+            Result::Many(words) => {
+                let types: Vec<WordType> = words.iter().map(|word| word.word_type).collect();
+                assert_eq!(types, vec![WordType::VerbGodanR, WordType::VerbIchidan]);
+            }
+            _ => panic!("かえる should be found twice"),
+        }
+    }
+
+    #[test]
+    fn search_missing_test() {
+        match search_word("ない言葉") {
+            Result::None => {}
+            _ => panic!("ない言葉 should not be found"),
+        }
+    }
+
+    #[test]
+    fn search_conjugated_test() {
+        match search_word("食べさせられなかった") {
+            Result::Single(word) => assert_eq!(word.dictionary_form, "食べる"),
+            _ => panic!("食べさせられなかった should be found once"),
+        }
+        match search_word("かえった") {
+            Result::Single(word) => assert_eq!(word.word_type, WordType::VerbGodanR),
+            _ => panic!("かえった should be found once"),
+        }
+    }
+
+    #[test]
+    fn search_derivations_test() {
+        let dictionary = Dictionary::bundled();
+        let derivations = search_derivations_in(&dictionary, "読ませられませんでした");
+        // causative passive and causative potential look the same
+        assert_eq!(derivations.len(), 2);
+        assert!(derivations
+            .iter()
+            .all(|derivation| derivation.word.dictionary_form == "読む"));
+        assert_eq!(derivations[0].steps.len(), 3);
+        assert_eq!(derivations[1].steps.len(), 2);
+        let derivations = search_derivations_in(&dictionary, "読む");
+        assert!(derivations[0].steps.is_empty());
+        let derivations = search_derivations_in(&dictionary, "しまった");
+        assert_eq!(derivations.len(), 1);
+        assert_eq!(derivations[0].word.word_type, WordType::AuxiliaryVerb);
+    }
+
+    #[test]
+    fn search_classical_test() {
+        match search_word("死ぬる") {
+            Result::Single(word) => {
+                assert_eq!(word.dictionary_form, "死ぬ");
+                assert_eq!(word.translation, "to die");
+                assert_eq!(word.word_type, WordType::VerbNahen);
+            }
+            _ => panic!("死ぬる should be found once"),
+        }
+        match search_word("行かむ") {
+            Result::Single(word) => {
+                assert_eq!(word.dictionary_form, "行く");
+                assert_eq!(word.word_type, WordType::VerbYodanK);
+            }
+            _ => panic!("行かむ should be found once"),
+        }
+        // a modern form is not taken for a classical one
+        match search_word("書け") {
+            Result::Single(word) => assert_eq!(word.word_type, WordType::VerbGodanK),
+            _ => panic!("書け should be found once"),
+        }
+    }
+
+    #[test]
+    fn search_local_dictionary_test() {
+        let dictionary = Dictionary::from_tsv("勉強\tべんきょう\tvs\tto study\n").unwrap();
+        match search_word_in(&dictionary, "勉強する") {
+            Result::Single(word) => assert_eq!(word.word_type, WordType::VerbSuru),
+            _ => panic!("勉強する should be found once"),
+        }
+        assert!(Dictionary::from_tsv("勉強\tべんきょう\n").is_err());
+        match search_word_in(&dictionary, "はいった") {
+            Result::Single(word) => {
+                assert_eq!(word.dictionary_form, "入る");
+                assert_eq!(word.word_type, WordType::VerbGodanR);
+            }
+            _ => panic!("はいった should be found once"),
+        }
+    }
 }
@@ -1,10 +1,24 @@
 use super::constant::*;
+use super::form::Base;
+use super::form::Form;
+use super::form::Polarity::*;
+use super::form::Politeness::*;
+use super::form::Tense::*;
+use super::inference;
+use super::keigo::{self, Keigo};
+use super::romaji;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 // The type is following EDICT classification
 // http://nihongo.monash.edu//jmdict_dtd_h.html
 pub enum WordType {
     VerbSuru,
+    VerbSuruS, // S Special
+    VerbSuruC, // C Classical, su instead of suru
+    VerbZuru,
     VerbKuru,
     VerbGodanARU,
     VerbGodanB,
@@ -22,23 +36,568 @@ pub enum WordType {
     VerbIchidan,
     AdjectiveI,
     AdjectiveNa,
-    //AdjectiveTaru, // TODO: implement this
-    //AdverbTo, // TODO: implement this
-    //Auxiliary, // TODO: implement this
-    //AuxiliaryVerb, // TODO: implement this
-    //AuxiliaryAdjective, // TODO: implement this
+    AdjectiveTaru,
+    AdverbTo,
+    Auxiliary,
+    AuxiliaryVerb,
+    AuxiliaryAdjective,
+    // classical words, upper nidan is kami nidan and lower nidan is shimo nidan
+    VerbYodanB,
+    VerbYodanG,
+    VerbYodanH,
+    VerbYodanK,
+    VerbYodanM,
+    VerbYodanN,
+    VerbYodanR,
+    VerbYodanS,
+    VerbYodanT,
+    VerbNidanLowerA,
+    VerbNidanUpperB,
+    VerbNidanLowerB,
+    VerbNidanUpperD,
+    VerbNidanLowerD,
+    VerbNidanUpperG,
+    VerbNidanLowerG,
+    VerbNidanUpperH,
+    VerbNidanLowerH,
+    VerbNidanUpperK,
+    VerbNidanLowerK,
+    VerbNidanUpperM,
+    VerbNidanLowerM,
+    VerbNidanLowerN,
+    VerbNidanUpperR,
+    VerbNidanLowerR,
+    VerbNidanLowerS,
+    VerbNidanUpperT,
+    VerbNidanLowerT,
+    VerbNidanLowerW,
+    VerbNidanUpperY,
+    VerbNidanLowerY,
+    VerbNidanLowerZ,
+    VerbRahen,
+    VerbNahen,
+    AdjectiveKu,
+    AdjectiveShiku,
 }
 
-#[derive(Debug)]
+impl WordType {
+    // map JMdict part of speech entity name, e.g. "v5k-s", to the word type
+    pub fn from_edict_tag(tag: &str) -> Option<WordType> {
+        match tag {
+            "vs" | "vs-i" => Some(WordType::VerbSuru),
+            "vs-s" => Some(WordType::VerbSuruS),
+            "vs-c" => Some(WordType::VerbSuruC),
+            "vz" => Some(WordType::VerbZuru),
+            "vk" => Some(WordType::VerbKuru),
+            "v5aru" => Some(WordType::VerbGodanARU),
+            "v5b" => Some(WordType::VerbGodanB),
+            "v5g" => Some(WordType::VerbGodanG),
+            "v5k" => Some(WordType::VerbGodanK),
+            "v5k-s" => Some(WordType::VerbGodanKS),
+            "v5m" => Some(WordType::VerbGodanM),
+            "v5n" => Some(WordType::VerbGodanN),
+            "v5r" => Some(WordType::VerbGodanR),
+            "v5r-i" => Some(WordType::VerbGodanRI),
+            "v5s" => Some(WordType::VerbGodanS),
+            "v5t" => Some(WordType::VerbGodanT),
+            "v5u" => Some(WordType::VerbGodanU),
+            "v5u-s" => Some(WordType::VerbGodanUS),
+            "v1" => Some(WordType::VerbIchidan),
+            "adj-i" | "adj-ix" => Some(WordType::AdjectiveI),
+            "adj-na" => Some(WordType::AdjectiveNa),
+            "adj-t" => Some(WordType::AdjectiveTaru),
+            "adv-to" => Some(WordType::AdverbTo),
+            "aux" => Some(WordType::Auxiliary),
+            "aux-v" => Some(WordType::AuxiliaryVerb),
+            "aux-adj" => Some(WordType::AuxiliaryAdjective),
+            "v4b" => Some(WordType::VerbYodanB),
+            "v4g" => Some(WordType::VerbYodanG),
+            "v4h" => Some(WordType::VerbYodanH),
+            "v4k" => Some(WordType::VerbYodanK),
+            "v4m" => Some(WordType::VerbYodanM),
+            "v4n" => Some(WordType::VerbYodanN),
+            "v4r" => Some(WordType::VerbYodanR),
+            "v4s" => Some(WordType::VerbYodanS),
+            "v4t" => Some(WordType::VerbYodanT),
+            "v2a-s" => Some(WordType::VerbNidanLowerA),
+            "v2b-k" => Some(WordType::VerbNidanUpperB),
+            "v2b-s" => Some(WordType::VerbNidanLowerB),
+            "v2d-k" => Some(WordType::VerbNidanUpperD),
+            "v2d-s" => Some(WordType::VerbNidanLowerD),
+            "v2g-k" => Some(WordType::VerbNidanUpperG),
+            "v2g-s" => Some(WordType::VerbNidanLowerG),
+            "v2h-k" => Some(WordType::VerbNidanUpperH),
+            "v2h-s" => Some(WordType::VerbNidanLowerH),
+            "v2k-k" => Some(WordType::VerbNidanUpperK),
+            "v2k-s" => Some(WordType::VerbNidanLowerK),
+            "v2m-k" => Some(WordType::VerbNidanUpperM),
+            "v2m-s" => Some(WordType::VerbNidanLowerM),
+            "v2n-s" => Some(WordType::VerbNidanLowerN),
+            "v2r-k" => Some(WordType::VerbNidanUpperR),
+            "v2r-s" => Some(WordType::VerbNidanLowerR),
+            "v2s-s" => Some(WordType::VerbNidanLowerS),
+            "v2t-k" => Some(WordType::VerbNidanUpperT),
+            "v2t-s" => Some(WordType::VerbNidanLowerT),
+            "v2w-s" => Some(WordType::VerbNidanLowerW),
+            "v2y-k" => Some(WordType::VerbNidanUpperY),
+            "v2y-s" => Some(WordType::VerbNidanLowerY),
+            "v2z-s" => Some(WordType::VerbNidanLowerZ),
+            "vr" => Some(WordType::VerbRahen),
+            "vn" => Some(WordType::VerbNahen),
+            "adj-ku" => Some(WordType::AdjectiveKu),
+            "adj-shiku" => Some(WordType::AdjectiveShiku),
+            _ => None,
+        }
+    }
+
+    // class name shown when a word is explained, e.g. godan-m
+    pub fn name(self) -> &'static str {
+        match self {
+            WordType::VerbSuru => "suru",
+            WordType::VerbSuruS => "suru special",
+            WordType::VerbSuruC => "su",
+            WordType::VerbZuru => "zuru",
+            WordType::VerbKuru => "kuru",
+            WordType::VerbGodanARU => "godan-aru",
+            WordType::VerbGodanB => "godan-b",
+            WordType::VerbGodanG => "godan-g",
+            WordType::VerbGodanK => "godan-k",
+            WordType::VerbGodanKS => "godan-k special",
+            WordType::VerbGodanM => "godan-m",
+            WordType::VerbGodanN => "godan-n",
+            WordType::VerbGodanR => "godan-r",
+            WordType::VerbGodanRI => "godan-r irregular",
+            WordType::VerbGodanS => "godan-s",
+            WordType::VerbGodanT => "godan-t",
+            WordType::VerbGodanU => "godan-u",
+            WordType::VerbGodanUS => "godan-u special",
+            WordType::VerbIchidan => "ichidan",
+            WordType::AdjectiveI => "i-adjective",
+            WordType::AdjectiveNa => "na-adjective",
+            WordType::AdjectiveTaru => "taru-adjective",
+            WordType::AdverbTo => "to-adverb",
+            WordType::Auxiliary => "auxiliary",
+            WordType::AuxiliaryVerb => "auxiliary verb",
+            WordType::AuxiliaryAdjective => "auxiliary adjective",
+            WordType::VerbYodanB => "yodan-b",
+            WordType::VerbYodanG => "yodan-g",
+            WordType::VerbYodanH => "yodan-h",
+            WordType::VerbYodanK => "yodan-k",
+            WordType::VerbYodanM => "yodan-m",
+            WordType::VerbYodanN => "yodan-n",
+            WordType::VerbYodanR => "yodan-r",
+            WordType::VerbYodanS => "yodan-s",
+            WordType::VerbYodanT => "yodan-t",
+            WordType::VerbNidanLowerA => "shimo nidan-a",
+            WordType::VerbNidanUpperB => "kami nidan-b",
+            WordType::VerbNidanLowerB => "shimo nidan-b",
+            WordType::VerbNidanUpperD => "kami nidan-d",
+            WordType::VerbNidanLowerD => "shimo nidan-d",
+            WordType::VerbNidanUpperG => "kami nidan-g",
+            WordType::VerbNidanLowerG => "shimo nidan-g",
+            WordType::VerbNidanUpperH => "kami nidan-h",
+            WordType::VerbNidanLowerH => "shimo nidan-h",
+            WordType::VerbNidanUpperK => "kami nidan-k",
+            WordType::VerbNidanLowerK => "shimo nidan-k",
+            WordType::VerbNidanUpperM => "kami nidan-m",
+            WordType::VerbNidanLowerM => "shimo nidan-m",
+            WordType::VerbNidanLowerN => "shimo nidan-n",
+            WordType::VerbNidanUpperR => "kami nidan-r",
+            WordType::VerbNidanLowerR => "shimo nidan-r",
+            WordType::VerbNidanLowerS => "shimo nidan-s",
+            WordType::VerbNidanUpperT => "kami nidan-t",
+            WordType::VerbNidanLowerT => "shimo nidan-t",
+            WordType::VerbNidanLowerW => "shimo nidan-w",
+            WordType::VerbNidanUpperY => "kami nidan-y",
+            WordType::VerbNidanLowerY => "shimo nidan-y",
+            WordType::VerbNidanLowerZ => "shimo nidan-z",
+            WordType::VerbRahen => "ra-hen",
+            WordType::VerbNahen => "na-hen",
+            WordType::AdjectiveKu => "ku-adjective",
+            WordType::AdjectiveShiku => "shiku-adjective",
+        }
+    }
+
+    // None when words of the type can end with anything, e.g. a noun taking suru
+    fn dictionary_ending(self) -> Option<&'static str> {
+        match self {
+            WordType::VerbSuru => None,
+            WordType::VerbSuruS => Some(SURU),
+            WordType::VerbSuruC => Some(GODAN_S_END),
+            WordType::VerbZuru => Some(ZURU),
+            WordType::VerbKuru => Some(GODAN_R_END),
+            WordType::VerbGodanARU => Some(GODAN_ARU_RU_END),
+            WordType::VerbGodanB => Some(GODAN_B_END),
+            WordType::VerbGodanG => Some(GODAN_G_END),
+            WordType::VerbGodanK => Some(GODAN_K_END),
+            WordType::VerbGodanKS => Some(GODAN_K_END),
+            WordType::VerbGodanM => Some(GODAN_M_END),
+            WordType::VerbGodanN => Some(GODAN_N_END),
+            WordType::VerbGodanR => Some(GODAN_R_END),
+            WordType::VerbGodanRI => Some(GODAN_R_END),
+            WordType::VerbGodanS => Some(GODAN_S_END),
+            WordType::VerbGodanT => Some(GODAN_T_END),
+            WordType::VerbGodanU => Some(GODAN_U_END),
+            WordType::VerbGodanUS => Some(GODAN_U_END),
+            WordType::VerbIchidan => Some(ICHIDAN_END),
+            WordType::AdjectiveI => Some(ADJ_I_END),
+            WordType::AdjectiveNa => None,
+            WordType::AdjectiveTaru => None,
+            WordType::AdverbTo => None,
+            WordType::Auxiliary => None,
+            WordType::AuxiliaryVerb => None,
+            WordType::AuxiliaryAdjective => Some(ADJ_I_END),
+            WordType::VerbYodanB => Some(GODAN_B_END),
+            WordType::VerbYodanG => Some(GODAN_G_END),
+            WordType::VerbYodanH => Some(NIDAN_H_END),
+            WordType::VerbYodanK => Some(GODAN_K_END),
+            WordType::VerbYodanM => Some(GODAN_M_END),
+            WordType::VerbYodanN => Some(GODAN_N_END),
+            WordType::VerbYodanR => Some(GODAN_R_END),
+            WordType::VerbYodanS => Some(GODAN_S_END),
+            WordType::VerbYodanT => Some(GODAN_T_END),
+            WordType::VerbNidanLowerA => Some(GODAN_U_END),
+            WordType::VerbNidanUpperB => Some(GODAN_B_END),
+            WordType::VerbNidanLowerB => Some(GODAN_B_END),
+            WordType::VerbNidanUpperD => Some(NIDAN_D_END),
+            WordType::VerbNidanLowerD => Some(NIDAN_D_END),
+            WordType::VerbNidanUpperG => Some(GODAN_G_END),
+            WordType::VerbNidanLowerG => Some(GODAN_G_END),
+            WordType::VerbNidanUpperH => Some(NIDAN_H_END),
+            WordType::VerbNidanLowerH => Some(NIDAN_H_END),
+            WordType::VerbNidanUpperK => Some(GODAN_K_END),
+            WordType::VerbNidanLowerK => Some(GODAN_K_END),
+            WordType::VerbNidanUpperM => Some(GODAN_M_END),
+            WordType::VerbNidanLowerM => Some(GODAN_M_END),
+            WordType::VerbNidanLowerN => Some(GODAN_N_END),
+            WordType::VerbNidanUpperR => Some(GODAN_R_END),
+            WordType::VerbNidanLowerR => Some(GODAN_R_END),
+            WordType::VerbNidanLowerS => Some(GODAN_S_END),
+            WordType::VerbNidanUpperT => Some(GODAN_T_END),
+            WordType::VerbNidanLowerT => Some(GODAN_T_END),
+            WordType::VerbNidanLowerW => Some(GODAN_U_END),
+            WordType::VerbNidanUpperY => Some(NIDAN_Y_END),
+            WordType::VerbNidanLowerY => Some(NIDAN_Y_END),
+            WordType::VerbNidanLowerZ => Some(NIDAN_Z_END),
+            WordType::VerbRahen => Some(RAHEN_END),
+            WordType::VerbNahen => Some(GODAN_N_END),
+            WordType::AdjectiveKu => Some(ADJ_KU_END),
+            WordType::AdjectiveShiku => Some(ADJ_KU_END),
+        }
+    }
+
+    pub fn edict_tag(self) -> &'static str {
+        match self {
+            WordType::VerbSuru => "vs",
+            WordType::VerbSuruS => "vs-s",
+            WordType::VerbSuruC => "vs-c",
+            WordType::VerbZuru => "vz",
+            WordType::VerbKuru => "vk",
+            WordType::VerbGodanARU => "v5aru",
+            WordType::VerbGodanB => "v5b",
+            WordType::VerbGodanG => "v5g",
+            WordType::VerbGodanK => "v5k",
+            WordType::VerbGodanKS => "v5k-s",
+            WordType::VerbGodanM => "v5m",
+            WordType::VerbGodanN => "v5n",
+            WordType::VerbGodanR => "v5r",
+            WordType::VerbGodanRI => "v5r-i",
+            WordType::VerbGodanS => "v5s",
+            WordType::VerbGodanT => "v5t",
+            WordType::VerbGodanU => "v5u",
+            WordType::VerbGodanUS => "v5u-s",
+            WordType::VerbIchidan => "v1",
+            WordType::AdjectiveI => "adj-i",
+            WordType::AdjectiveNa => "adj-na",
+            WordType::AdjectiveTaru => "adj-t",
+            WordType::AdverbTo => "adv-to",
+            WordType::Auxiliary => "aux",
+            WordType::AuxiliaryVerb => "aux-v",
+            WordType::AuxiliaryAdjective => "aux-adj",
+            WordType::VerbYodanB => "v4b",
+            WordType::VerbYodanG => "v4g",
+            WordType::VerbYodanH => "v4h",
+            WordType::VerbYodanK => "v4k",
+            WordType::VerbYodanM => "v4m",
+            WordType::VerbYodanN => "v4n",
+            WordType::VerbYodanR => "v4r",
+            WordType::VerbYodanS => "v4s",
+            WordType::VerbYodanT => "v4t",
+            WordType::VerbNidanLowerA => "v2a-s",
+            WordType::VerbNidanUpperB => "v2b-k",
+            WordType::VerbNidanLowerB => "v2b-s",
+            WordType::VerbNidanUpperD => "v2d-k",
+            WordType::VerbNidanLowerD => "v2d-s",
+            WordType::VerbNidanUpperG => "v2g-k",
+            WordType::VerbNidanLowerG => "v2g-s",
+            WordType::VerbNidanUpperH => "v2h-k",
+            WordType::VerbNidanLowerH => "v2h-s",
+            WordType::VerbNidanUpperK => "v2k-k",
+            WordType::VerbNidanLowerK => "v2k-s",
+            WordType::VerbNidanUpperM => "v2m-k",
+            WordType::VerbNidanLowerM => "v2m-s",
+            WordType::VerbNidanLowerN => "v2n-s",
+            WordType::VerbNidanUpperR => "v2r-k",
+            WordType::VerbNidanLowerR => "v2r-s",
+            WordType::VerbNidanLowerS => "v2s-s",
+            WordType::VerbNidanUpperT => "v2t-k",
+            WordType::VerbNidanLowerT => "v2t-s",
+            WordType::VerbNidanLowerW => "v2w-s",
+            WordType::VerbNidanUpperY => "v2y-k",
+            WordType::VerbNidanLowerY => "v2y-s",
+            WordType::VerbNidanLowerZ => "v2z-s",
+            WordType::VerbRahen => "vr",
+            WordType::VerbNahen => "vn",
+            WordType::AdjectiveKu => "adj-ku",
+            WordType::AdjectiveShiku => "adj-shiku",
+        }
+    }
+
+    // the conjugation class of a classical word and the row of its ending
+    fn bungo(self) -> Option<Bungo> {
+        match self {
+            WordType::VerbYodanB => Some(Bungo::Yodan(BA_ROW)),
+            WordType::VerbYodanG => Some(Bungo::Yodan(GA_ROW)),
+            WordType::VerbYodanH => Some(Bungo::Yodan(HA_ROW)),
+            WordType::VerbYodanK => Some(Bungo::Yodan(KA_ROW)),
+            WordType::VerbYodanM => Some(Bungo::Yodan(MA_ROW)),
+            WordType::VerbYodanN => Some(Bungo::Yodan(NA_ROW)),
+            WordType::VerbYodanR => Some(Bungo::Yodan(RA_ROW)),
+            WordType::VerbYodanS => Some(Bungo::Yodan(SA_ROW)),
+            WordType::VerbYodanT => Some(Bungo::Yodan(TA_ROW)),
+            WordType::VerbNidanLowerA => Some(Bungo::LowerNidan(A_ROW)),
+            WordType::VerbNidanUpperB => Some(Bungo::UpperNidan(BA_ROW)),
+            WordType::VerbNidanLowerB => Some(Bungo::LowerNidan(BA_ROW)),
+            WordType::VerbNidanUpperD => Some(Bungo::UpperNidan(DA_ROW)),
+            WordType::VerbNidanLowerD => Some(Bungo::LowerNidan(DA_ROW)),
+            WordType::VerbNidanUpperG => Some(Bungo::UpperNidan(GA_ROW)),
+            WordType::VerbNidanLowerG => Some(Bungo::LowerNidan(GA_ROW)),
+            WordType::VerbNidanUpperH => Some(Bungo::UpperNidan(HA_ROW)),
+            WordType::VerbNidanLowerH => Some(Bungo::LowerNidan(HA_ROW)),
+            WordType::VerbNidanUpperK => Some(Bungo::UpperNidan(KA_ROW)),
+            WordType::VerbNidanLowerK => Some(Bungo::LowerNidan(KA_ROW)),
+            WordType::VerbNidanUpperM => Some(Bungo::UpperNidan(MA_ROW)),
+            WordType::VerbNidanLowerM => Some(Bungo::LowerNidan(MA_ROW)),
+            WordType::VerbNidanLowerN => Some(Bungo::LowerNidan(NA_ROW)),
+            WordType::VerbNidanUpperR => Some(Bungo::UpperNidan(RA_ROW)),
+            WordType::VerbNidanLowerR => Some(Bungo::LowerNidan(RA_ROW)),
+            WordType::VerbNidanLowerS => Some(Bungo::LowerNidan(SA_ROW)),
+            WordType::VerbNidanUpperT => Some(Bungo::UpperNidan(TA_ROW)),
+            WordType::VerbNidanLowerT => Some(Bungo::LowerNidan(TA_ROW)),
+            WordType::VerbNidanLowerW => Some(Bungo::LowerNidan(WA_ROW)),
+            WordType::VerbNidanUpperY => Some(Bungo::UpperNidan(YA_ROW)),
+            WordType::VerbNidanLowerY => Some(Bungo::LowerNidan(YA_ROW)),
+            WordType::VerbNidanLowerZ => Some(Bungo::LowerNidan(ZA_ROW)),
+            WordType::VerbRahen => Some(Bungo::Rahen),
+            WordType::VerbNahen => Some(Bungo::Nahen),
+            WordType::AdjectiveKu => Some(Bungo::Ku),
+            WordType::AdjectiveShiku => Some(Bungo::Shiku),
+            _ => None,
+        }
+    }
+
+    fn is_classical(self) -> bool {
+        self.bungo().is_some()
+    }
+
+    // the modern word types written the same as the classical one in the dictionary,
+    // e.g. 行く is a godan verb that was a yodan verb and 死ぬ a na-hen verb
+    pub fn modern_types(self) -> &'static [WordType] {
+        match self {
+            WordType::VerbYodanB => &[WordType::VerbGodanB],
+            WordType::VerbYodanG => &[WordType::VerbGodanG],
+            WordType::VerbYodanK => &[WordType::VerbGodanK, WordType::VerbGodanKS],
+            WordType::VerbYodanM => &[WordType::VerbGodanM],
+            WordType::VerbYodanR => &[WordType::VerbGodanR],
+            WordType::VerbYodanS => &[WordType::VerbGodanS],
+            WordType::VerbYodanT => &[WordType::VerbGodanT],
+            WordType::VerbNahen => &[WordType::VerbGodanN],
+            _ => &[],
+        }
+    }
+}
+
+// classical conjugation classes, the verbs keep the a, i, u and e kana of their row
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Bungo {
+    Yodan([&'static str; 4]),
+    UpperNidan([&'static str; 4]),
+    LowerNidan([&'static str; 4]),
+    Rahen,
+    Nahen,
+    Ku,
+    Shiku,
+}
+
+impl FromStr for WordType {
+    type Err = ConjugationError;
+
+    fn from_str(tag: &str) -> Result<WordType, ConjugationError> {
+        WordType::from_edict_tag(tag)
+            .ok_or_else(|| ConjugationError::UnsupportedWordType(String::from(tag)))
+    }
+}
+
+// why a word cannot be conjugated
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConjugationError {
+    EmptyWord,
+    // the word does not end the way words of the type end
+    WrongEnding(String, WordType),
+    // part of speech without conjugation rules
+    UnsupportedWordType(String),
+}
+
+impl fmt::Display for ConjugationError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConjugationError::EmptyWord => write!(formatter, "the word is empty"),
+            ConjugationError::WrongEnding(word, word_type) => write!(
+                formatter,
+                "{} does not end like a {} word",
+                word,
+                word_type.name()
+            ),
+            ConjugationError::UnsupportedWordType(tag) => {
+                write!(formatter, "{} words cannot be conjugated", tag)
+            }
+        }
+    }
+}
+
+impl Error for ConjugationError {}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct WordEntity {
     pub dictionary_form: String,
+    pub reading: String,
     pub translation: String,
     pub word_type: WordType,
 }
 
+// one conjugated form written with kanji, in hiragana and in romaji
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conjugation {
+    pub kanji: String,
+    pub kana: String,
+    pub romaji: String,
+}
+
 impl WordEntity {
+    // None when the form does not exist for the word type
+    pub fn conjugate(&self, form: Form) -> Result<Option<String>, ConjugationError> {
+        self.check()?;
+        Ok(self.conjugated(form))
+    }
+
+    // the form of both the dictionary form and the reading
+    pub fn conjugation(&self, form: Form) -> Result<Option<Conjugation>, ConjugationError> {
+        self.check()?;
+        let kanji = match self.conjugated(form) {
+            Some(kanji) => kanji,
+            None => return Ok(None),
+        };
+        let kana = self.reading_entity().conjugated(form).unwrap_or_default();
+        Ok(Some(Conjugation {
+            kanji,
+            romaji: romaji::to_romaji(&kana),
+            kana,
+        }))
+    }
+
+    // every form that exists for the word type
+    pub fn forms(&self) -> Result<impl Iterator<Item = (Form, String)> + '_, ConjugationError> {
+        self.check()?;
+        Ok(Form::all().filter_map(move |form| Some((form, self.conjugated(form)?))))
+    }
+
+    fn conjugated(&self, form: Form) -> Option<String> {
+        match self.word_type {
+            WordType::AdjectiveTaru | WordType::AdverbTo => return self.to_conjugated(form),
+            WordType::Auxiliary => return self.copula_conjugated(form),
+            WordType::VerbSuruS | WordType::VerbSuruC | WordType::VerbZuru => {
+                return self.suru_variant_conjugated(form)
+            }
+            WordType::AuxiliaryVerb if self.dictionary_form.ends_with(MASU) => {
+                return self.masu_conjugated(form)
+            }
+            WordType::AuxiliaryVerb | WordType::AuxiliaryAdjective => {
+                return self.auxiliary_entity()?.conjugated(form)
+            }
+            word_type if word_type.is_classical() => return self.classical_conjugated(form),
+            _ => {}
+        }
+        let conjugated = match form {
+            Form::Indicative(tense, polarity, politeness) => match (tense, polarity, politeness) {
+                (NonPast, Affirmative, Plain) => self.imperfective_form(),
+                (NonPast, Negative, Plain) => self.imperfective_negative_form(),
+                (Past, Affirmative, Plain) => self.perfective_form(),
+                (Past, Negative, Plain) => self.perfective_negative_form(),
+                (NonPast, Affirmative, Polite) => self.formal_imperfective_form(),
+                (NonPast, Negative, Polite) => self.formal_imperfective_negative_form(),
+                (Past, Affirmative, Polite) => self.formal_perfective_form(),
+                (Past, Negative, Polite) => self.formal_perfective_negative_form(),
+            },
+            Form::NaiDesu(NonPast) => self.formal_imperfective_negative_variant_form(),
+            Form::NaiDesu(Past) => self.formal_perfective_negative_variant_form(),
+            Form::Potential(tense, polarity, politeness) => match (tense, polarity, politeness) {
+                (NonPast, Affirmative, Plain) => self.informal_potential_form(),
+                (NonPast, Negative, Plain) => self.informal_potential_negative_form(),
+                (Past, Affirmative, Plain) => self.informal_perfective_potential_form(),
+                (Past, Negative, Plain) => self.informal_perfective_potential_negative_form(),
+                (NonPast, Affirmative, Polite) => self.formal_potential_form(),
+                (NonPast, Negative, Polite) => self.formal_potential_negative_form(),
+                (Past, Affirmative, Polite) => self.formal_perfective_potential_form(),
+                (Past, Negative, Polite) => self.formal_perfective_potential_negative_form(),
+            },
+            Form::Imperative(polarity, politeness) => match (polarity, politeness) {
+                (Affirmative, Plain) => self.informal_imperative_form(),
+                (Negative, Plain) => self.informal_imperative_negative_form(),
+                (Affirmative, Polite) => self.formal_imperative_form(),
+                (Negative, Polite) => self.formal_imperative_negative_form(),
+            },
+            Form::WrittenImperative => self.written_imperative_form(),
+            Form::Volitional(polarity, politeness) => match (polarity, politeness) {
+                (Affirmative, Plain) => self.volitional_form(),
+                (Negative, Plain) => self.volitional_negative_form(),
+                (Affirmative, Polite) => self.formal_volitional_form(),
+                (Negative, Polite) => self.formal_volitional_negative_form(),
+            },
+            Form::ShortNegativeVolitional => self.volitional_negative_variant_form(),
+            Form::Te(Affirmative) => self.te_form(),
+            Form::Te(Negative) => self.negative_te_form(),
+            Form::Nakute => self.negative_te_variant_form(),
+            // only taru adjectives and to adverbs have one
+            Form::Adverbial => String::from(NOT_APPLICABLE),
+            Form::Progressive(tense, polarity, politeness) => match (tense, polarity, politeness) {
+                (NonPast, Affirmative, Plain) => self.progressive_form(),
+                (NonPast, Negative, Plain) => self.progressive_negative_form(),
+                (Past, Affirmative, Plain) => self.perfective_progressive_form(),
+                (Past, Negative, Plain) => self.perfective_progressive_negative_form(),
+                (NonPast, Affirmative, Polite) => self.formal_progressive_form(),
+                (NonPast, Negative, Polite) => self.formal_progressive_negative_form(),
+                (Past, Affirmative, Polite) => self.formal_perfective_progressive_form(),
+                (Past, Negative, Polite) => self.formal_perfective_progressive_negative_form(),
+            },
+            Form::Provisional(Affirmative) => self.provisional_form(),
+            Form::Provisional(Negative) => self.provisional_negative_form(),
+            Form::Conditional(Affirmative) => self.conditional_form(),
+            Form::Conditional(Negative) => self.conditional_negative_form(),
+            Form::Hypothetical(Affirmative) => self.hypothetical_form(),
+            Form::Hypothetical(Negative) => self.hypothetical_negative_form(),
+            // only classical words have bases
+            Form::Classical(_) | Form::ClassicalNegative | Form::ClassicalVolitional => {
+                String::from(NOT_APPLICABLE)
+            }
+        };
+        if conjugated == NOT_APPLICABLE {
+            return None;
+        }
+        Some(conjugated)
+    }
+
     // dictionary form, short form, informal form
-    pub fn imperfective_form(&self) -> String {
+    fn imperfective_form(&self) -> String {
         match self.word_type {
             WordType::VerbSuru => {
                 if self.dictionary_form.ends_with(SURU) {
@@ -63,7 +622,7 @@ impl WordEntity {
     }
 
     // negative form, short negative form, informal negative form
-    pub fn imperfective_negative_form(&self) -> String {
+    fn imperfective_negative_form(&self) -> String {
         match self.word_type {
             WordType::VerbSuru => {
                 let stem = self.get_suru_stem(self.dictionary_form.clone());
@@ -132,11 +691,55 @@ impl WordEntity {
                 let stem = self.get_adj_na_stem(self.dictionary_form.clone());
                 [stem, String::from(JANAI)].join("")
             }
+            WordType::VerbSuruS
+            | WordType::VerbSuruC
+            | WordType::VerbZuru
+            | WordType::AdjectiveTaru
+            | WordType::AdverbTo
+            | WordType::Auxiliary
+            | WordType::AuxiliaryVerb
+            | WordType::AuxiliaryAdjective
+            | WordType::VerbYodanB
+            | WordType::VerbYodanG
+            | WordType::VerbYodanH
+            | WordType::VerbYodanK
+            | WordType::VerbYodanM
+            | WordType::VerbYodanN
+            | WordType::VerbYodanR
+            | WordType::VerbYodanS
+            | WordType::VerbYodanT
+            | WordType::VerbNidanLowerA
+            | WordType::VerbNidanUpperB
+            | WordType::VerbNidanLowerB
+            | WordType::VerbNidanUpperD
+            | WordType::VerbNidanLowerD
+            | WordType::VerbNidanUpperG
+            | WordType::VerbNidanLowerG
+            | WordType::VerbNidanUpperH
+            | WordType::VerbNidanLowerH
+            | WordType::VerbNidanUpperK
+            | WordType::VerbNidanLowerK
+            | WordType::VerbNidanUpperM
+            | WordType::VerbNidanLowerM
+            | WordType::VerbNidanLowerN
+            | WordType::VerbNidanUpperR
+            | WordType::VerbNidanLowerR
+            | WordType::VerbNidanLowerS
+            | WordType::VerbNidanUpperT
+            | WordType::VerbNidanLowerT
+            | WordType::VerbNidanLowerW
+            | WordType::VerbNidanUpperY
+            | WordType::VerbNidanLowerY
+            | WordType::VerbNidanLowerZ
+            | WordType::VerbRahen
+            | WordType::VerbNahen
+            | WordType::AdjectiveKu
+            | WordType::AdjectiveShiku => String::from(NOT_APPLICABLE),
         }
     }
 
     // past form, ta form, past informal form
-    pub fn perfective_form(&self) -> String {
+    fn perfective_form(&self) -> String {
         match self.word_type {
             WordType::VerbSuru => {
                 let stem = self.get_suru_stem(self.dictionary_form.clone());
@@ -222,11 +825,55 @@ impl WordEntity {
                 let stem = self.get_adj_na_stem(self.dictionary_form.clone());
                 [stem, String::from(DATTA)].join("")
             }
+            WordType::VerbSuruS
+            | WordType::VerbSuruC
+            | WordType::VerbZuru
+            | WordType::AdjectiveTaru
+            | WordType::AdverbTo
+            | WordType::Auxiliary
+            | WordType::AuxiliaryVerb
+            | WordType::AuxiliaryAdjective
+            | WordType::VerbYodanB
+            | WordType::VerbYodanG
+            | WordType::VerbYodanH
+            | WordType::VerbYodanK
+            | WordType::VerbYodanM
+            | WordType::VerbYodanN
+            | WordType::VerbYodanR
+            | WordType::VerbYodanS
+            | WordType::VerbYodanT
+            | WordType::VerbNidanLowerA
+            | WordType::VerbNidanUpperB
+            | WordType::VerbNidanLowerB
+            | WordType::VerbNidanUpperD
+            | WordType::VerbNidanLowerD
+            | WordType::VerbNidanUpperG
+            | WordType::VerbNidanLowerG
+            | WordType::VerbNidanUpperH
+            | WordType::VerbNidanLowerH
+            | WordType::VerbNidanUpperK
+            | WordType::VerbNidanLowerK
+            | WordType::VerbNidanUpperM
+            | WordType::VerbNidanLowerM
+            | WordType::VerbNidanLowerN
+            | WordType::VerbNidanUpperR
+            | WordType::VerbNidanLowerR
+            | WordType::VerbNidanLowerS
+            | WordType::VerbNidanUpperT
+            | WordType::VerbNidanLowerT
+            | WordType::VerbNidanLowerW
+            | WordType::VerbNidanUpperY
+            | WordType::VerbNidanLowerY
+            | WordType::VerbNidanLowerZ
+            | WordType::VerbRahen
+            | WordType::VerbNahen
+            | WordType::AdjectiveKu
+            | WordType::AdjectiveShiku => String::from(NOT_APPLICABLE),
         }
     }
 
     // past negative form, ta negative form, past informal negative form
-    pub fn perfective_negative_form(&self) -> String {
+    fn perfective_negative_form(&self) -> String {
         [
             self.trim_string(self.imperfective_negative_form(), I_KANA),
             String::from(KATTA),
@@ -235,7 +882,7 @@ impl WordEntity {
     }
 
     // masu form, long form, polite form
-    pub fn formal_imperfective_form(&self) -> String {
+    fn formal_imperfective_form(&self) -> String {
         match self.word_type {
             WordType::VerbSuru => {
                 let stem = self.get_suru_stem(self.dictionary_form.clone());
@@ -302,16 +949,69 @@ impl WordEntity {
                 let stem = self.get_ichidan_stem(self.dictionary_form.clone());
                 [stem, String::from(MASU)].join("")
             }
-            WordType::AdjectiveI => String::from(NOT_APPLICABLE),
-            WordType::AdjectiveNa => String::from(NOT_APPLICABLE),
+            WordType::AdjectiveI => [self.dictionary_form.clone(), String::from(DESU)].join(""),
+            WordType::AdjectiveNa => {
+                let stem = self.get_adj_na_stem(self.dictionary_form.clone());
+                [stem, String::from(DESU)].join("")
+            }
+            WordType::VerbSuruS
+            | WordType::VerbSuruC
+            | WordType::VerbZuru
+            | WordType::AdjectiveTaru
+            | WordType::AdverbTo
+            | WordType::Auxiliary
+            | WordType::AuxiliaryVerb
+            | WordType::AuxiliaryAdjective
+            | WordType::VerbYodanB
+            | WordType::VerbYodanG
+            | WordType::VerbYodanH
+            | WordType::VerbYodanK
+            | WordType::VerbYodanM
+            | WordType::VerbYodanN
+            | WordType::VerbYodanR
+            | WordType::VerbYodanS
+            | WordType::VerbYodanT
+            | WordType::VerbNidanLowerA
+            | WordType::VerbNidanUpperB
+            | WordType::VerbNidanLowerB
+            | WordType::VerbNidanUpperD
+            | WordType::VerbNidanLowerD
+            | WordType::VerbNidanUpperG
+            | WordType::VerbNidanLowerG
+            | WordType::VerbNidanUpperH
+            | WordType::VerbNidanLowerH
+            | WordType::VerbNidanUpperK
+            | WordType::VerbNidanLowerK
+            | WordType::VerbNidanUpperM
+            | WordType::VerbNidanLowerM
+            | WordType::VerbNidanLowerN
+            | WordType::VerbNidanUpperR
+            | WordType::VerbNidanLowerR
+            | WordType::VerbNidanLowerS
+            | WordType::VerbNidanUpperT
+            | WordType::VerbNidanLowerT
+            | WordType::VerbNidanLowerW
+            | WordType::VerbNidanUpperY
+            | WordType::VerbNidanLowerY
+            | WordType::VerbNidanLowerZ
+            | WordType::VerbRahen
+            | WordType::VerbNahen
+            | WordType::AdjectiveKu
+            | WordType::AdjectiveShiku => String::from(NOT_APPLICABLE),
         }
     }
 
     // masu negative form, long negative form, polite negative form
-    pub fn formal_imperfective_negative_form(&self) -> String {
+    fn formal_imperfective_negative_form(&self) -> String {
         match self.word_type {
-            WordType::AdjectiveI => String::from(NOT_APPLICABLE),
-            WordType::AdjectiveNa => String::from(NOT_APPLICABLE),
+            WordType::AdjectiveI => {
+                let stem = self.get_adj_i_stem(self.dictionary_form.clone());
+                [stem, String::from(KU_ARIMASEN)].join("")
+            }
+            WordType::AdjectiveNa => {
+                let stem = self.get_adj_na_stem(self.dictionary_form.clone());
+                [stem, String::from(DEWA_ARIMASEN)].join("")
+            }
             _ => [
                 self.trim_string(self.formal_imperfective_form(), GODAN_S_END),
                 String::from(SEN),
@@ -320,11 +1020,19 @@ impl WordEntity {
         }
     }
 
+    // nai desu form, informal negative form + desu
+    fn formal_imperfective_negative_variant_form(&self) -> String {
+        [self.imperfective_negative_form(), String::from(DESU)].join("")
+    }
+
     // masu past form, long past form, polite past form
-    pub fn formal_perfective_form(&self) -> String {
+    fn formal_perfective_form(&self) -> String {
         match self.word_type {
-            WordType::AdjectiveI => String::from(NOT_APPLICABLE),
-            WordType::AdjectiveNa => String::from(NOT_APPLICABLE),
+            WordType::AdjectiveI => [self.perfective_form(), String::from(DESU)].join(""),
+            WordType::AdjectiveNa => {
+                let stem = self.get_adj_na_stem(self.dictionary_form.clone());
+                [stem, String::from(DESHITA)].join("")
+            }
             _ => [
                 self.trim_string(self.formal_imperfective_form(), GODAN_S_END),
                 String::from(SHITA),
@@ -334,10 +1042,13 @@ impl WordEntity {
     }
 
     // masu past negative form, long past negative form, polite past negative form
-    pub fn formal_perfective_negative_form(&self) -> String {
+    fn formal_perfective_negative_form(&self) -> String {
         match self.word_type {
-            WordType::AdjectiveI => String::from(NOT_APPLICABLE),
-            WordType::AdjectiveNa => String::from(NOT_APPLICABLE),
+            WordType::AdjectiveI | WordType::AdjectiveNa => [
+                self.formal_imperfective_negative_form(),
+                String::from(DESHITA),
+            ]
+            .join(""),
             _ => [
                 self.trim_string(self.formal_imperfective_form(), GODAN_S_END),
                 String::from(SEN),
@@ -347,8 +1058,13 @@ impl WordEntity {
         }
     }
 
+    // nakatta desu form, informal past negative form + desu
+    fn formal_perfective_negative_variant_form(&self) -> String {
+        [self.perfective_negative_form(), String::from(DESU)].join("")
+    }
+
     // e form
-    pub fn informal_potential_form(&self) -> String {
+    fn informal_potential_form(&self) -> String {
         match self.word_type {
             WordType::VerbSuru => {
                 let stem = self.get_suru_stem(self.dictionary_form.clone());
@@ -418,11 +1134,55 @@ impl WordEntity {
             .join(""),
             WordType::AdjectiveI => String::from(NOT_APPLICABLE),
             WordType::AdjectiveNa => String::from(NOT_APPLICABLE),
+            WordType::VerbSuruS
+            | WordType::VerbSuruC
+            | WordType::VerbZuru
+            | WordType::AdjectiveTaru
+            | WordType::AdverbTo
+            | WordType::Auxiliary
+            | WordType::AuxiliaryVerb
+            | WordType::AuxiliaryAdjective
+            | WordType::VerbYodanB
+            | WordType::VerbYodanG
+            | WordType::VerbYodanH
+            | WordType::VerbYodanK
+            | WordType::VerbYodanM
+            | WordType::VerbYodanN
+            | WordType::VerbYodanR
+            | WordType::VerbYodanS
+            | WordType::VerbYodanT
+            | WordType::VerbNidanLowerA
+            | WordType::VerbNidanUpperB
+            | WordType::VerbNidanLowerB
+            | WordType::VerbNidanUpperD
+            | WordType::VerbNidanLowerD
+            | WordType::VerbNidanUpperG
+            | WordType::VerbNidanLowerG
+            | WordType::VerbNidanUpperH
+            | WordType::VerbNidanLowerH
+            | WordType::VerbNidanUpperK
+            | WordType::VerbNidanLowerK
+            | WordType::VerbNidanUpperM
+            | WordType::VerbNidanLowerM
+            | WordType::VerbNidanLowerN
+            | WordType::VerbNidanUpperR
+            | WordType::VerbNidanLowerR
+            | WordType::VerbNidanLowerS
+            | WordType::VerbNidanUpperT
+            | WordType::VerbNidanLowerT
+            | WordType::VerbNidanLowerW
+            | WordType::VerbNidanUpperY
+            | WordType::VerbNidanLowerY
+            | WordType::VerbNidanLowerZ
+            | WordType::VerbRahen
+            | WordType::VerbNahen
+            | WordType::AdjectiveKu
+            | WordType::AdjectiveShiku => String::from(NOT_APPLICABLE),
         }
     }
 
     // e negative form
-    pub fn informal_potential_negative_form(&self) -> String {
+    fn informal_potential_negative_form(&self) -> String {
         match self.word_type {
             WordType::AdjectiveI => String::from(NOT_APPLICABLE),
             WordType::AdjectiveNa => String::from(NOT_APPLICABLE),
@@ -435,7 +1195,7 @@ impl WordEntity {
     }
 
     // e past form
-    pub fn informal_perfective_potential_form(&self) -> String {
+    fn informal_perfective_potential_form(&self) -> String {
         match self.word_type {
             WordType::AdjectiveI => String::from(NOT_APPLICABLE),
             WordType::AdjectiveNa => String::from(NOT_APPLICABLE),
@@ -448,7 +1208,7 @@ impl WordEntity {
     }
 
     // e past negative form
-    pub fn informal_perfective_potential_negative_form(&self) -> String {
+    fn informal_perfective_potential_negative_form(&self) -> String {
         match self.word_type {
             WordType::AdjectiveI => String::from(NOT_APPLICABLE),
             WordType::AdjectiveNa => String::from(NOT_APPLICABLE),
@@ -461,7 +1221,7 @@ impl WordEntity {
     }
 
     // e masu form
-    pub fn formal_potential_form(&self) -> String {
+    fn formal_potential_form(&self) -> String {
         match self.word_type {
             WordType::AdjectiveI => String::from(NOT_APPLICABLE),
             WordType::AdjectiveNa => String::from(NOT_APPLICABLE),
@@ -474,7 +1234,7 @@ impl WordEntity {
     }
 
     // e masen form
-    pub fn formal_potential_negative_form(&self) -> String {
+    fn formal_potential_negative_form(&self) -> String {
         match self.word_type {
             WordType::AdjectiveI => String::from(NOT_APPLICABLE),
             WordType::AdjectiveNa => String::from(NOT_APPLICABLE),
@@ -487,7 +1247,7 @@ impl WordEntity {
     }
 
     // e past masu form
-    pub fn formal_perfective_potential_form(&self) -> String {
+    fn formal_perfective_potential_form(&self) -> String {
         match self.word_type {
             WordType::AdjectiveI => String::from(NOT_APPLICABLE),
             WordType::AdjectiveNa => String::from(NOT_APPLICABLE),
@@ -500,7 +1260,7 @@ impl WordEntity {
     }
 
     // e past masen form
-    pub fn formal_perfective_potential_negative_form(&self) -> String {
+    fn formal_perfective_potential_negative_form(&self) -> String {
         match self.word_type {
             WordType::AdjectiveI => String::from(NOT_APPLICABLE),
             WordType::AdjectiveNa => String::from(NOT_APPLICABLE),
@@ -513,62 +1273,1148 @@ impl WordEntity {
         }
     }
 
-    pub fn informal_imperative_form(&self) -> String {
-        // TODO: implement this
-        self.dictionary_form.clone()
-    }
-
-    pub fn informal_imperative_negative_form(&self) -> String {
-        // TODO: implement this
-        self.dictionary_form.clone()
+    // command form, e form
+    fn informal_imperative_form(&self) -> String {
+        match self.word_type {
+            WordType::VerbSuru => {
+                let stem = self.get_suru_stem(self.dictionary_form.clone());
+                [stem, String::from(SHIRO)].join("")
+            }
+            WordType::VerbKuru => {
+                let stem = self.get_kuru_stem(self.dictionary_form.clone());
+                [stem, String::from(KOI)].join("")
+            }
+            WordType::VerbGodanARU => {
+                let stem = self.get_godan_aru_stem(self.dictionary_form.clone());
+                if self.dictionary_form.ends_with(GODAN_ARU_SHA_END) {
+                    return [stem, String::from(SHAI)].join("");
+                }
+                [stem, String::from(SAI)].join("")
+            }
+            WordType::VerbGodanB => {
+                let stem = self.get_godan_b_stem(self.dictionary_form.clone());
+                [stem, String::from(BE)].join("")
+            }
+            WordType::VerbGodanG => {
+                let stem = self.get_godan_g_stem(self.dictionary_form.clone());
+                [stem, String::from(GE)].join("")
+            }
+            WordType::VerbGodanK | WordType::VerbGodanKS => {
+                let stem = self.get_godan_k_stem(self.dictionary_form.clone());
+                [stem, String::from(KE)].join("")
+            }
+            WordType::VerbGodanM => {
+                let stem = self.get_godan_m_stem(self.dictionary_form.clone());
+                [stem, String::from(ME)].join("")
+            }
+            WordType::VerbGodanN => {
+                let stem = self.get_godan_n_stem(self.dictionary_form.clone());
+                [stem, String::from(NE)].join("")
+            }
+            WordType::VerbGodanR => {
+                let stem = self.get_godan_r_stem(self.dictionary_form.clone());
+                [stem, String::from(RE)].join("")
+            }
+            WordType::VerbGodanRI => {
+                let stem = self.get_godan_ri_stem(self.dictionary_form.clone());
+                if stem.is_empty() {
+                    if self.dictionary_form.starts_with(ARU) {
+                        return [ARU_STEM, RE].join("");
+                    }
+                    return [ARU_STEM_KANA, RE].join("");
+                }
+                [stem, String::from(RE)].join("")
+            }
+            WordType::VerbGodanS => {
+                let stem = self.get_godan_s_stem(self.dictionary_form.clone());
+                [stem, String::from(SE)].join("")
+            }
+            WordType::VerbGodanT => {
+                let stem = self.get_godan_t_stem(self.dictionary_form.clone());
+                [stem, String::from(TE)].join("")
+            }
+            WordType::VerbGodanU | WordType::VerbGodanUS => {
+                let stem = self.get_godan_u_stem(self.dictionary_form.clone());
+                [stem, String::from(E)].join("")
+            }
+            WordType::VerbIchidan => {
+                let stem = self.get_ichidan_stem(self.dictionary_form.clone());
+                [stem, String::from(RO)].join("")
+            }
+            WordType::AdjectiveI => String::from(NOT_APPLICABLE),
+            WordType::AdjectiveNa => String::from(NOT_APPLICABLE),
+            WordType::VerbSuruS
+            | WordType::VerbSuruC
+            | WordType::VerbZuru
+            | WordType::AdjectiveTaru
+            | WordType::AdverbTo
+            | WordType::Auxiliary
+            | WordType::AuxiliaryVerb
+            | WordType::AuxiliaryAdjective
+            | WordType::VerbYodanB
+            | WordType::VerbYodanG
+            | WordType::VerbYodanH
+            | WordType::VerbYodanK
+            | WordType::VerbYodanM
+            | WordType::VerbYodanN
+            | WordType::VerbYodanR
+            | WordType::VerbYodanS
+            | WordType::VerbYodanT
+            | WordType::VerbNidanLowerA
+            | WordType::VerbNidanUpperB
+            | WordType::VerbNidanLowerB
+            | WordType::VerbNidanUpperD
+            | WordType::VerbNidanLowerD
+            | WordType::VerbNidanUpperG
+            | WordType::VerbNidanLowerG
+            | WordType::VerbNidanUpperH
+            | WordType::VerbNidanLowerH
+            | WordType::VerbNidanUpperK
+            | WordType::VerbNidanLowerK
+            | WordType::VerbNidanUpperM
+            | WordType::VerbNidanLowerM
+            | WordType::VerbNidanLowerN
+            | WordType::VerbNidanUpperR
+            | WordType::VerbNidanLowerR
+            | WordType::VerbNidanLowerS
+            | WordType::VerbNidanUpperT
+            | WordType::VerbNidanLowerT
+            | WordType::VerbNidanLowerW
+            | WordType::VerbNidanUpperY
+            | WordType::VerbNidanLowerY
+            | WordType::VerbNidanLowerZ
+            | WordType::VerbRahen
+            | WordType::VerbNahen
+            | WordType::AdjectiveKu
+            | WordType::AdjectiveShiku => String::from(NOT_APPLICABLE),
+        }
     }
 
-    pub fn formal_imperative_form(&self) -> String {
-        // TODO: implement this
-        self.dictionary_form.clone()
+    // prohibitive form, na form
+    fn informal_imperative_negative_form(&self) -> String {
+        match self.word_type {
+            WordType::AdjectiveI => String::from(NOT_APPLICABLE),
+            WordType::AdjectiveNa => String::from(NOT_APPLICABLE),
+            _ => [self.imperfective_form(), String::from(NA)].join(""),
+        }
     }
 
-    pub fn formal_imperative_negative_form(&self) -> String {
-        // TODO: implement this
-        self.dictionary_form.clone()
+    // nasai form, masu stem + nasai
+    fn formal_imperative_form(&self) -> String {
+        match self.word_type {
+            WordType::VerbGodanARU => [
+                self.trim_string(self.formal_imperfective_form(), MASU),
+                String::from(MASE),
+            ]
+            .join(""),
+            WordType::AdjectiveI => String::from(NOT_APPLICABLE),
+            WordType::AdjectiveNa => String::from(NOT_APPLICABLE),
+            _ => [
+                self.trim_string(self.formal_imperfective_form(), MASU),
+                String::from(NASAI),
+            ]
+            .join(""),
+        }
     }
 
-    pub fn volitional_form(&self) -> String {
-        // TODO: implement this
-        self.dictionary_form.clone()
+    // nai de kudasai form
+    fn formal_imperative_negative_form(&self) -> String {
+        match self.word_type {
+            WordType::AdjectiveI => String::from(NOT_APPLICABLE),
+            WordType::AdjectiveNa => String::from(NOT_APPLICABLE),
+            _ => [self.imperfective_negative_form(), String::from(DE_KUDASAI)].join(""),
+        }
     }
 
-    pub fn volitional_negative_form(&self) -> String {
-        // TODO: implement this
-        self.dictionary_form.clone()
+    // written command form, yo form
+    fn written_imperative_form(&self) -> String {
+        match self.word_type {
+            WordType::VerbSuru => {
+                let stem = self.get_suru_stem(self.dictionary_form.clone());
+                [stem, String::from(SEYO)].join("")
+            }
+            WordType::VerbKuru => {
+                let stem = self.get_kuru_stem(self.dictionary_form.clone());
+                [stem, String::from(KOYO)].join("")
+            }
+            WordType::VerbIchidan => {
+                let stem = self.get_ichidan_stem(self.dictionary_form.clone());
+                [stem, String::from(YO)].join("")
+            }
+            _ => self.informal_imperative_form(),
+        }
     }
 
-    // others
-
-    // stem
-    fn get_suru_stem(&self, word: String) -> String {
-        self.trim_string(word, SURU)
+    // let's form, o form
+    fn volitional_form(&self) -> String {
+        match self.word_type {
+            WordType::VerbSuru => {
+                let stem = self.get_suru_stem(self.dictionary_form.clone());
+                [stem, String::from(SHIYOU)].join("")
+            }
+            WordType::VerbKuru => {
+                let stem = self.get_kuru_stem(self.dictionary_form.clone());
+                [stem, String::from(KOYOU)].join("")
+            }
+            WordType::VerbGodanARU => {
+                let stem = self.get_godan_aru_stem(self.dictionary_form.clone());
+                if self.dictionary_form.ends_with(GODAN_ARU_SHA_END) {
+                    return [stem, String::from(SHAROU)].join("");
+                }
+                [stem, String::from(SAROU)].join("")
+            }
+            WordType::VerbGodanB => {
+                let stem = self.get_godan_b_stem(self.dictionary_form.clone());
+                [stem, String::from(BOU)].join("")
+            }
+            WordType::VerbGodanG => {
+                let stem = self.get_godan_g_stem(self.dictionary_form.clone());
+                [stem, String::from(GOU)].join("")
+            }
+            WordType::VerbGodanK | WordType::VerbGodanKS => {
+                let stem = self.get_godan_k_stem(self.dictionary_form.clone());
+                [stem, String::from(KOU)].join("")
+            }
+            WordType::VerbGodanM => {
+                let stem = self.get_godan_m_stem(self.dictionary_form.clone());
+                [stem, String::from(MOU)].join("")
+            }
+            WordType::VerbGodanN => {
+                let stem = self.get_godan_n_stem(self.dictionary_form.clone());
+                [stem, String::from(NOU)].join("")
+            }
+            WordType::VerbGodanR => {
+                let stem = self.get_godan_r_stem(self.dictionary_form.clone());
+                [stem, String::from(ROU)].join("")
+            }
+            WordType::VerbGodanRI => {
+                let stem = self.get_godan_ri_stem(self.dictionary_form.clone());
+                if stem.is_empty() {
+                    if self.dictionary_form.starts_with(ARU) {
+                        return [ARU_STEM, ROU].join("");
+                    }
+                    return [ARU_STEM_KANA, ROU].join("");
+                }
+                [stem, String::from(ROU)].join("")
+            }
+            WordType::VerbGodanS => {
+                let stem = self.get_godan_s_stem(self.dictionary_form.clone());
+                [stem, String::from(SOU)].join("")
+            }
+            WordType::VerbGodanT => {
+                let stem = self.get_godan_t_stem(self.dictionary_form.clone());
+                [stem, String::from(TOU)].join("")
+            }
+            WordType::VerbGodanU | WordType::VerbGodanUS => {
+                let stem = self.get_godan_u_stem(self.dictionary_form.clone());
+                [stem, String::from(OU)].join("")
+            }
+            WordType::VerbIchidan => {
+                let stem = self.get_ichidan_stem(self.dictionary_form.clone());
+                [stem, String::from(YOU)].join("")
+            }
+            WordType::AdjectiveI => {
+                let stem = self.get_adj_i_stem(self.dictionary_form.clone());
+                [stem, String::from(KAROU)].join("")
+            }
+            WordType::AdjectiveNa => {
+                let stem = self.get_adj_na_stem(self.dictionary_form.clone());
+                [stem, String::from(DAROU)].join("")
+            }
+            WordType::VerbSuruS
+            | WordType::VerbSuruC
+            | WordType::VerbZuru
+            | WordType::AdjectiveTaru
+            | WordType::AdverbTo
+            | WordType::Auxiliary
+            | WordType::AuxiliaryVerb
+            | WordType::AuxiliaryAdjective
+            | WordType::VerbYodanB
+            | WordType::VerbYodanG
+            | WordType::VerbYodanH
+            | WordType::VerbYodanK
+            | WordType::VerbYodanM
+            | WordType::VerbYodanN
+            | WordType::VerbYodanR
+            | WordType::VerbYodanS
+            | WordType::VerbYodanT
+            | WordType::VerbNidanLowerA
+            | WordType::VerbNidanUpperB
+            | WordType::VerbNidanLowerB
+            | WordType::VerbNidanUpperD
+            | WordType::VerbNidanLowerD
+            | WordType::VerbNidanUpperG
+            | WordType::VerbNidanLowerG
+            | WordType::VerbNidanUpperH
+            | WordType::VerbNidanLowerH
+            | WordType::VerbNidanUpperK
+            | WordType::VerbNidanLowerK
+            | WordType::VerbNidanUpperM
+            | WordType::VerbNidanLowerM
+            | WordType::VerbNidanLowerN
+            | WordType::VerbNidanUpperR
+            | WordType::VerbNidanLowerR
+            | WordType::VerbNidanLowerS
+            | WordType::VerbNidanUpperT
+            | WordType::VerbNidanLowerT
+            | WordType::VerbNidanLowerW
+            | WordType::VerbNidanUpperY
+            | WordType::VerbNidanLowerY
+            | WordType::VerbNidanLowerZ
+            | WordType::VerbRahen
+            | WordType::VerbNahen
+            | WordType::AdjectiveKu
+            | WordType::AdjectiveShiku => String::from(NOT_APPLICABLE),
+        }
     }
 
-    fn get_kuru_stem(&self, word: String) -> String {
-        if word.ends_with(KURU_KANA) {
-            return self.trim_string(word, KURU_KANA);
+    // mashou form
+    fn formal_volitional_form(&self) -> String {
+        match self.word_type {
+            WordType::AdjectiveI => String::from(NOT_APPLICABLE),
+            WordType::AdjectiveNa => String::from(NOT_APPLICABLE),
+            _ => [
+                self.trim_string(self.formal_imperfective_form(), MASU),
+                String::from(MASHOU),
+            ]
+            .join(""),
         }
-        self.trim_string(word, KURU)
     }
 
-    fn get_godan_aru_stem(&self, word: String) -> String {
-        if word.ends_with(GODAN_ARU_SHA_END) {
-            return self.trim_string(word, GODAN_ARU_SHA_END);
-        }
-        if word.ends_with(GODAN_ARU_SA_END) {
-            return self.trim_string(word, GODAN_ARU_SA_END);
+    // mai form, dictionary form + mai
+    fn volitional_negative_form(&self) -> String {
+        match self.word_type {
+            WordType::AdjectiveI => {
+                let stem = self.get_adj_i_stem(self.dictionary_form.clone());
+                [stem, String::from(KU_ARUMAI)].join("")
+            }
+            WordType::AdjectiveNa => {
+                let stem = self.get_adj_na_stem(self.dictionary_form.clone());
+                [stem, String::from(DEWA_ARUMAI)].join("")
+            }
+            _ => [self.imperfective_form(), String::from(MAI)].join(""),
         }
-        self.trim_string(word, GODAN_ARU_RU_END)
     }
 
-    fn get_godan_b_stem(&self, word: String) -> String {
-        self.trim_string(word, GODAN_B_END)
+    // masu mai form
+    fn formal_volitional_negative_form(&self) -> String {
+        match self.word_type {
+            WordType::AdjectiveI => String::from(NOT_APPLICABLE),
+            WordType::AdjectiveNa => String::from(NOT_APPLICABLE),
+            _ => [self.formal_imperfective_form(), String::from(MAI)].join(""),
+        }
+    }
+
+    // mai form attached to the stem instead, e.g. shimai, komai, tabemai
+    fn volitional_negative_variant_form(&self) -> String {
+        match self.word_type {
+            WordType::VerbSuru => {
+                let stem = self.get_suru_stem(self.dictionary_form.clone());
+                [stem, String::from(SHIMAI)].join("")
+            }
+            WordType::VerbKuru => {
+                let stem = self.get_kuru_stem(self.dictionary_form.clone());
+                [stem, String::from(KOMAI)].join("")
+            }
+            WordType::VerbIchidan => {
+                let stem = self.get_ichidan_stem(self.dictionary_form.clone());
+                [stem, String::from(MAI)].join("")
+            }
+            _ => self.volitional_negative_form(),
+        }
+    }
+
+    // te form, conjunctive form
+    fn te_form(&self) -> String {
+        match self.word_type {
+            WordType::AdjectiveI => {
+                let stem = self.get_adj_i_stem(self.dictionary_form.clone());
+                [stem, String::from(KUTE)].join("")
+            }
+            WordType::AdjectiveNa => {
+                let stem = self.get_adj_na_stem(self.dictionary_form.clone());
+                [stem, String::from(DE)].join("")
+            }
+            // the sound change is the same as the past form
+            _ => {
+                let perfective = self.perfective_form();
+                if perfective.ends_with(DA) {
+                    return [self.trim_string(perfective, DA), String::from(DE)].join("");
+                }
+                [self.trim_string(perfective, TA), String::from(TE)].join("")
+            }
+        }
+    }
+
+    // nai de form, adjectives only have the nakute form
+    fn negative_te_form(&self) -> String {
+        match self.word_type {
+            WordType::AdjectiveI => self.negative_te_variant_form(),
+            WordType::AdjectiveNa => self.negative_te_variant_form(),
+            _ => [self.imperfective_negative_form(), String::from(DE)].join(""),
+        }
+    }
+
+    // nakute form
+    fn negative_te_variant_form(&self) -> String {
+        [
+            self.trim_string(self.imperfective_negative_form(), I_KANA),
+            String::from(KUTE),
+        ]
+        .join("")
+    }
+
+    // te iru form
+    fn progressive_form(&self) -> String {
+        match self.progressive_entity() {
+            Some(progressive) => progressive.imperfective_form(),
+            None => String::from(NOT_APPLICABLE),
+        }
+    }
+
+    // te inai form
+    fn progressive_negative_form(&self) -> String {
+        match self.progressive_entity() {
+            Some(progressive) => progressive.imperfective_negative_form(),
+            None => String::from(NOT_APPLICABLE),
+        }
+    }
+
+    // te ita form
+    fn perfective_progressive_form(&self) -> String {
+        match self.progressive_entity() {
+            Some(progressive) => progressive.perfective_form(),
+            None => String::from(NOT_APPLICABLE),
+        }
+    }
+
+    // te inakatta form
+    fn perfective_progressive_negative_form(&self) -> String {
+        match self.progressive_entity() {
+            Some(progressive) => progressive.perfective_negative_form(),
+            None => String::from(NOT_APPLICABLE),
+        }
+    }
+
+    // te imasu form
+    fn formal_progressive_form(&self) -> String {
+        match self.progressive_entity() {
+            Some(progressive) => progressive.formal_imperfective_form(),
+            None => String::from(NOT_APPLICABLE),
+        }
+    }
+
+    // te imasen form
+    fn formal_progressive_negative_form(&self) -> String {
+        match self.progressive_entity() {
+            Some(progressive) => progressive.formal_imperfective_negative_form(),
+            None => String::from(NOT_APPLICABLE),
+        }
+    }
+
+    // te imashita form
+    fn formal_perfective_progressive_form(&self) -> String {
+        match self.progressive_entity() {
+            Some(progressive) => progressive.formal_perfective_form(),
+            None => String::from(NOT_APPLICABLE),
+        }
+    }
+
+    // te imasen deshita form
+    fn formal_perfective_progressive_negative_form(&self) -> String {
+        match self.progressive_entity() {
+            Some(progressive) => progressive.formal_perfective_negative_form(),
+            None => String::from(NOT_APPLICABLE),
+        }
+    }
+
+    // ba form, provisional conditional
+    fn provisional_form(&self) -> String {
+        match self.word_type {
+            WordType::VerbSuru => {
+                let stem = self.get_suru_stem(self.dictionary_form.clone());
+                [stem, String::from(SUREBA)].join("")
+            }
+            WordType::VerbKuru => {
+                let stem = self.get_kuru_stem(self.dictionary_form.clone());
+                [stem, String::from(KUREBA)].join("")
+            }
+            WordType::VerbIchidan => {
+                let stem = self.get_ichidan_stem(self.dictionary_form.clone());
+                [stem, String::from(REBA)].join("")
+            }
+            WordType::AdjectiveI => {
+                let stem = self.get_adj_i_stem(self.dictionary_form.clone());
+                [stem, String::from(KEREBA)].join("")
+            }
+            WordType::AdjectiveNa => {
+                let stem = self.get_adj_na_stem(self.dictionary_form.clone());
+                [stem, String::from(NARA)].join("")
+            }
+            // godan verbs share the e row with the potential form
+            _ => [
+                self.trim_string(self.informal_potential_form(), GODAN_ARU_RU_END),
+                String::from(BA),
+            ]
+            .join(""),
+        }
+    }
+
+    // nakereba form
+    fn provisional_negative_form(&self) -> String {
+        [
+            self.trim_string(self.imperfective_negative_form(), I_KANA),
+            String::from(KEREBA),
+        ]
+        .join("")
+    }
+
+    // tara form, past form + ra
+    fn conditional_form(&self) -> String {
+        [self.perfective_form(), String::from(RA)].join("")
+    }
+
+    // nakattara form
+    fn conditional_negative_form(&self) -> String {
+        [self.perfective_negative_form(), String::from(RA)].join("")
+    }
+
+    // nara form, dictionary form + nara
+    fn hypothetical_form(&self) -> String {
+        [self.imperfective_form(), String::from(NARA)].join("")
+    }
+
+    // nai nara form
+    fn hypothetical_negative_form(&self) -> String {
+        [self.imperfective_negative_form(), String::from(NARA)].join("")
+    }
+
+    // rareru form, conjugates like an ichidan verb
+    pub fn passive(&self) -> Result<Option<WordEntity>, ConjugationError> {
+        self.check()?;
+        Ok(self.derived_entity(WordEntity::passive_dictionary_form, WordType::VerbIchidan))
+    }
+
+    // saseru form, conjugates like an ichidan verb
+    pub fn causative(&self) -> Result<Option<WordEntity>, ConjugationError> {
+        self.check()?;
+        Ok(self.derived_entity(WordEntity::causative_dictionary_form, WordType::VerbIchidan))
+    }
+
+    // colloquial sasu form, conjugates like a godan su verb
+    pub fn short_causative(&self) -> Result<Option<WordEntity>, ConjugationError> {
+        self.check()?;
+        Ok(self.derived_entity(
+            WordEntity::short_causative_dictionary_form,
+            WordType::VerbGodanS,
+        ))
+    }
+
+    // saserareru form
+    pub fn causative_passive(&self) -> Result<Option<WordEntity>, ConjugationError> {
+        match self.causative()? {
+            Some(causative) => causative.passive(),
+            None => Ok(None),
+        }
+    }
+
+    // o ni naru form, or the verb used instead, e.g. 召し上がる for 食べる,
+    // verbs with a one kana stem take the rareru form, e.g. 来られる
+    pub fn honorific(&self) -> Result<Option<WordEntity>, ConjugationError> {
+        self.check()?;
+        Ok(self.keigo_entity(Keigo::Honorific))
+    }
+
+    // o suru form, or the verb used instead, e.g. 参る for 行く
+    pub fn humble(&self) -> Result<Option<WordEntity>, ConjugationError> {
+        self.check()?;
+        Ok(self.keigo_entity(Keigo::Humble))
+    }
+
+    // sareru form, only for godan verbs not ending in su
+    pub fn short_causative_passive(&self) -> Result<Option<WordEntity>, ConjugationError> {
+        match self.word_type {
+            WordType::VerbSuru
+            | WordType::VerbKuru
+            | WordType::VerbGodanS
+            | WordType::VerbIchidan
+            | WordType::AdjectiveI
+            | WordType::AdjectiveNa
+            | WordType::AdjectiveTaru
+            | WordType::AdverbTo
+            | WordType::Auxiliary
+            | WordType::AuxiliaryAdjective => {
+                self.check()?;
+                Ok(None)
+            }
+            WordType::AuxiliaryVerb => {
+                self.check()?;
+                match self.auxiliary_entity() {
+                    Some(entity) => entity.short_causative_passive(),
+                    None => Ok(None),
+                }
+            }
+            WordType::VerbSuruS | WordType::VerbSuruC | WordType::VerbZuru => {
+                self.check()?;
+                match self.regular_entity() {
+                    Some(entity) => entity.short_causative_passive(),
+                    None => Ok(None),
+                }
+            }
+            word_type if word_type.is_classical() => {
+                self.check()?;
+                Ok(None)
+            }
+            _ => match self.short_causative()? {
+                Some(short_causative) => short_causative.passive(),
+                None => Ok(None),
+            },
+        }
+    }
+
+    // others
+
+    // te iru conjugates like an ichidan verb
+    fn progressive_entity(&self) -> Option<WordEntity> {
+        self.derived_entity(
+            WordEntity::progressive_dictionary_form,
+            WordType::VerbIchidan,
+        )
+    }
+
+    fn progressive_dictionary_form(&self) -> Option<String> {
+        match self.word_type {
+            // existence has no progressive, 有っている is not said
+            WordType::VerbGodanRI => None,
+            WordType::AdjectiveI => None,
+            WordType::AdjectiveNa => None,
+            _ => Some([self.te_form(), String::from(IRU)].join("")),
+        }
+    }
+
+    fn passive_dictionary_form(&self) -> Option<String> {
+        match self.word_type {
+            WordType::VerbSuru => {
+                let stem = self.get_suru_stem(self.dictionary_form.clone());
+                Some([stem, String::from(SARERU)].join(""))
+            }
+            WordType::VerbKuru => {
+                let stem = self.get_kuru_stem(self.dictionary_form.clone());
+                Some([stem, String::from(KORARERU)].join(""))
+            }
+            WordType::VerbIchidan => {
+                let stem = self.get_ichidan_stem(self.dictionary_form.clone());
+                Some([stem, String::from(RARERU)].join(""))
+            }
+            WordType::AdjectiveI => None,
+            WordType::AdjectiveNa => None,
+            WordType::AdjectiveTaru
+            | WordType::AdverbTo
+            | WordType::Auxiliary
+            | WordType::AuxiliaryAdjective => None,
+            WordType::AuxiliaryVerb => self.auxiliary_entity()?.passive_dictionary_form(),
+            WordType::VerbSuruS | WordType::VerbSuruC | WordType::VerbZuru => {
+                self.regular_entity()?.passive_dictionary_form()
+            }
+            word_type if word_type.is_classical() => None,
+            _ => Some([self.get_godan_a_row_stem(), String::from(RERU)].join("")),
+        }
+    }
+
+    fn causative_dictionary_form(&self) -> Option<String> {
+        match self.word_type {
+            WordType::VerbSuru => {
+                let stem = self.get_suru_stem(self.dictionary_form.clone());
+                Some([stem, String::from(SASERU)].join(""))
+            }
+            WordType::VerbKuru => {
+                let stem = self.get_kuru_stem(self.dictionary_form.clone());
+                Some([stem, String::from(KOSASERU)].join(""))
+            }
+            WordType::VerbIchidan => {
+                let stem = self.get_ichidan_stem(self.dictionary_form.clone());
+                Some([stem, String::from(SASERU)].join(""))
+            }
+            WordType::AdjectiveI => None,
+            WordType::AdjectiveNa => None,
+            WordType::AdjectiveTaru
+            | WordType::AdverbTo
+            | WordType::Auxiliary
+            | WordType::AuxiliaryAdjective => None,
+            WordType::AuxiliaryVerb => self.auxiliary_entity()?.causative_dictionary_form(),
+            WordType::VerbSuruS | WordType::VerbSuruC | WordType::VerbZuru => {
+                self.regular_entity()?.causative_dictionary_form()
+            }
+            word_type if word_type.is_classical() => None,
+            _ => Some([self.get_godan_a_row_stem(), String::from(SERU)].join("")),
+        }
+    }
+
+    fn short_causative_dictionary_form(&self) -> Option<String> {
+        match self.word_type {
+            WordType::VerbSuru => {
+                let stem = self.get_suru_stem(self.dictionary_form.clone());
+                Some([stem, String::from(SASU)].join(""))
+            }
+            WordType::VerbKuru => {
+                let stem = self.get_kuru_stem(self.dictionary_form.clone());
+                Some([stem, String::from(KOSASU)].join(""))
+            }
+            WordType::VerbIchidan => {
+                let stem = self.get_ichidan_stem(self.dictionary_form.clone());
+                Some([stem, String::from(SASU)].join(""))
+            }
+            WordType::AdjectiveI => None,
+            WordType::AdjectiveNa => None,
+            WordType::AdjectiveTaru
+            | WordType::AdverbTo
+            | WordType::Auxiliary
+            | WordType::AuxiliaryAdjective => None,
+            WordType::AuxiliaryVerb => self.auxiliary_entity()?.short_causative_dictionary_form(),
+            WordType::VerbSuruS | WordType::VerbSuruC | WordType::VerbZuru => {
+                self.regular_entity()?.short_causative_dictionary_form()
+            }
+            word_type if word_type.is_classical() => None,
+            _ => Some([self.get_godan_a_row_stem(), String::from(GODAN_S_END)].join("")),
+        }
+    }
+
+    // special suru verbs conjugate like suru verbs, e.g. 察しない, 察するな, but their
+    // potential is seraru, e.g. 察せられる, and some take the negative and potential
+    // of the su verb, e.g. 愛さない, 愛せる, zuru verbs conjugate like jiru verbs,
+    // e.g. 信じない, except for the forms keeping zuru
+    fn suru_variant_conjugated(&self, form: Form) -> Option<String> {
+        match (self.word_type, form) {
+            (_, Form::DICTIONARY) => Some(self.dictionary_form.clone()),
+            (WordType::VerbSuruS, Form::Indicative(_, Negative, Plain))
+            | (WordType::VerbSuruS, Form::NaiDesu(_))
+            | (WordType::VerbSuruS, Form::Potential(..))
+            | (WordType::VerbSuruS, Form::Imperative(Negative, Polite))
+            | (WordType::VerbSuruS, Form::Te(Negative))
+            | (WordType::VerbSuruS, Form::Nakute)
+            | (WordType::VerbSuruS, Form::Provisional(Negative))
+            | (WordType::VerbSuruS, Form::Conditional(Negative))
+            | (WordType::VerbSuruS, Form::Hypothetical(Negative))
+                if self.has_su_forms() =>
+            {
+                self.derived_entity(WordEntity::su_dictionary_form, WordType::VerbGodanS)?
+                    .conjugated(form)
+            }
+            (WordType::VerbSuruS, Form::Potential(tense, polarity, politeness)) => self
+                .derived_entity(WordEntity::seraru_dictionary_form, WordType::VerbIchidan)?
+                .conjugated(Form::Indicative(tense, polarity, politeness)),
+            (WordType::VerbZuru, Form::Provisional(Affirmative)) => {
+                let stem = self.get_zuru_stem(self.dictionary_form.clone());
+                Some([stem, String::from(ZUREBA)].join(""))
+            }
+            (WordType::VerbZuru, Form::Hypothetical(Affirmative)) => {
+                Some([self.dictionary_form.clone(), String::from(NARA)].join(""))
+            }
+            (WordType::VerbZuru, Form::WrittenImperative) => {
+                let stem = self.get_zuru_stem(self.dictionary_form.clone());
+                Some([stem, String::from(ZEYO)].join(""))
+            }
+            _ => self.regular_entity()?.conjugated(form),
+        }
+    }
+
+    // the verb the word conjugates like, e.g. 察する as a suru verb, 愛す as a godan su verb
+    // and 信じる for 信ずる
+    fn regular_entity(&self) -> Option<WordEntity> {
+        match self.word_type {
+            WordType::VerbSuruS => Some(WordEntity {
+                word_type: WordType::VerbSuru,
+                ..self.clone()
+            }),
+            WordType::VerbSuruC => Some(WordEntity {
+                word_type: WordType::VerbGodanS,
+                ..self.clone()
+            }),
+            WordType::VerbZuru => {
+                self.derived_entity(WordEntity::regular_dictionary_form, WordType::VerbIchidan)
+            }
+            _ => None,
+        }
+    }
+
+    // the stem or its reading is listed, e.g. 愛 but not 察
+    fn has_su_forms(&self) -> bool {
+        let stem = self.get_suru_stem(self.dictionary_form.clone());
+        SU_FORM_STEMS
+            .iter()
+            .any(|(kanji, kana)| stem == *kanji || stem == *kana)
+    }
+
+    fn su_dictionary_form(&self) -> Option<String> {
+        let stem = self.get_suru_stem(self.dictionary_form.clone());
+        Some([stem, String::from(GODAN_S_END)].join(""))
+    }
+
+    fn seraru_dictionary_form(&self) -> Option<String> {
+        let stem = self.get_suru_stem(self.dictionary_form.clone());
+        Some([stem, String::from(SERARERU)].join(""))
+    }
+
+    fn regular_dictionary_form(&self) -> Option<String> {
+        match self.word_type {
+            WordType::VerbZuru => {
+                let stem = self.get_zuru_stem(self.dictionary_form.clone());
+                Some([stem, String::from(JIRU)].join(""))
+            }
+            _ => None,
+        }
+    }
+
+    fn keigo_entity(&self, keigo: Keigo) -> Option<WordEntity> {
+        if let Some((dictionary_form, word_type)) =
+            keigo::suppletive(&self.dictionary_form, self.word_type, keigo)
+        {
+            let reading = keigo::suppletive(&self.reading, self.word_type, keigo)
+                .map(|(reading, _)| reading)
+                .or_else(|| keigo::reading(&dictionary_form))
+                .unwrap_or_else(|| dictionary_form.clone());
+            return Some(WordEntity {
+                dictionary_form,
+                reading,
+                translation: self.translation.clone(),
+                word_type,
+            });
+        }
+        let short_stem = self.reading_entity().get_masu_stem().chars().count() <= 1;
+        match (self.word_type, keigo) {
+            (WordType::VerbSuru, Keigo::Honorific) => {
+                self.derived_entity(WordEntity::nasaru_dictionary_form, WordType::VerbGodanARU)
+            }
+            (WordType::VerbSuru, Keigo::Humble) => {
+                self.derived_entity(WordEntity::itasu_dictionary_form, WordType::VerbGodanS)
+            }
+            // already honorific, e.g. 下さる
+            (WordType::VerbGodanARU, _) => None,
+            (WordType::VerbKuru, _)
+            | (WordType::VerbGodanB, _)
+            | (WordType::VerbGodanG, _)
+            | (WordType::VerbGodanK, _)
+            | (WordType::VerbGodanKS, _)
+            | (WordType::VerbGodanM, _)
+            | (WordType::VerbGodanN, _)
+            | (WordType::VerbGodanR, _)
+            | (WordType::VerbGodanRI, _)
+            | (WordType::VerbGodanS, _)
+            | (WordType::VerbGodanT, _)
+            | (WordType::VerbGodanU, _)
+            | (WordType::VerbGodanUS, _)
+            | (WordType::VerbIchidan, _)
+            | (WordType::VerbSuruS, _)
+            | (WordType::VerbSuruC, _)
+            | (WordType::VerbZuru, _) => match keigo {
+                Keigo::Honorific if short_stem => {
+                    self.derived_entity(WordEntity::passive_dictionary_form, WordType::VerbIchidan)
+                }
+                Keigo::Honorific => {
+                    self.derived_entity(WordEntity::o_ni_naru_dictionary_form, WordType::VerbGodanR)
+                }
+                Keigo::Humble if short_stem => None,
+                Keigo::Humble => {
+                    self.derived_entity(WordEntity::o_suru_dictionary_form, WordType::VerbSuru)
+                }
+            },
+            _ => None,
+        }
+    }
+
+    fn nasaru_dictionary_form(&self) -> Option<String> {
+        let stem = self.get_suru_stem(self.dictionary_form.clone());
+        Some([stem, String::from(NASARU)].join(""))
+    }
+
+    fn itasu_dictionary_form(&self) -> Option<String> {
+        let stem = self.get_suru_stem(self.dictionary_form.clone());
+        Some([stem, String::from(ITASU)].join(""))
+    }
+
+    fn o_ni_naru_dictionary_form(&self) -> Option<String> {
+        Some([O, &self.get_masu_stem(), NI_NARU].join(""))
+    }
+
+    fn o_suru_dictionary_form(&self) -> Option<String> {
+        Some([O, &self.get_masu_stem(), SURU].join(""))
+    }
+
+    // taru adjectives and to adverbs are used with shite iru, e.g. 堂々としている
+    fn to_conjugated(&self, form: Form) -> Option<String> {
+        let stem = self.get_to_stem(self.dictionary_form.clone());
+        match form {
+            Form::DICTIONARY => {
+                if self.word_type == WordType::AdjectiveTaru {
+                    return Some([stem, String::from(TARU)].join(""));
+                }
+                Some(self.dictionary_form.clone())
+            }
+            Form::Te(Affirmative) => Some([stem, String::from(TOSHITE)].join("")),
+            Form::Adverbial => Some([stem, String::from(TO)].join("")),
+            Form::Indicative(..)
+            | Form::NaiDesu(_)
+            | Form::Provisional(_)
+            | Form::Conditional(_) => self
+                .derived_entity(WordEntity::to_dictionary_form, WordType::VerbIchidan)?
+                .conjugated(form),
+            _ => None,
+        }
+    }
+
+    fn to_dictionary_form(&self) -> Option<String> {
+        let stem = self.get_to_stem(self.dictionary_form.clone());
+        Some([stem, String::from(TOSHITEIRU)].join(""))
+    }
+
+    // auxiliaries ending with the copula conjugate like it, e.g. そうだ, そうじゃない,
+    // the others such as う, よう or まい only have their dictionary form
+    fn copula_conjugated(&self, form: Form) -> Option<String> {
+        let copula = match self.derived_entity(WordEntity::copula_stem, WordType::AdjectiveNa) {
+            Some(copula) => copula,
+            None if form == Form::DICTIONARY => return Some(self.dictionary_form.clone()),
+            None => return None,
+        };
+        // the plain form of です is だ
+        if form == Form::DICTIONARY {
+            return Some([copula.dictionary_form, String::from(DA)].join(""));
+        }
+        copula.conjugated(form)
+    }
+
+    fn copula_stem(&self) -> Option<String> {
+        if !self.dictionary_form.ends_with(DA) && !self.dictionary_form.ends_with(DESU) {
+            return None;
+        }
+        Some(self.get_copula_stem(self.dictionary_form.clone()))
+    }
+
+    // masu only has the polite forms of a verb, e.g. ません, ましょう
+    fn masu_conjugated(&self, form: Form) -> Option<String> {
+        let polite = match form {
+            Form::Indicative(tense, polarity, Plain) => Form::Indicative(tense, polarity, Polite),
+            Form::Volitional(polarity, Plain) => Form::Volitional(polarity, Polite),
+            _ => return None,
+        };
+        self.derived_entity(WordEntity::masu_dictionary_form, WordType::VerbIchidan)?
+            .conjugated(polite)
+    }
+
+    fn masu_dictionary_form(&self) -> Option<String> {
+        let stem = self.trim_string(self.dictionary_form.clone(), MASU);
+        Some([stem, String::from(ICHIDAN_END)].join(""))
+    }
+
+    // auxiliary adjectives conjugate like i adjectives, e.g. たい,
+    // auxiliary verbs like the verb they end with, e.g. しまう
+    fn auxiliary_entity(&self) -> Option<WordEntity> {
+        let word_type = match self.word_type {
+            WordType::AuxiliaryAdjective => WordType::AdjectiveI,
+            _ => self.auxiliary_verb_type()?,
+        };
+        Some(WordEntity {
+            word_type,
+            ..self.clone()
+        })
+    }
+
+    // the type of the verb an auxiliary verb ends with, ru after the i and e columns
+    // is ichidan, e.g. みる, くれる, and godan otherwise, e.g. たがる
+    fn auxiliary_verb_type(&self) -> Option<WordType> {
+        let kana = if self.reading.is_empty() {
+            &self.dictionary_form
+        } else {
+            &self.reading
+        };
+        if kana.ends_with(MASU) {
+            return None;
+        }
+        inference::special_ending_type(kana).or_else(|| inference::ending_verb_type(kana))
+    }
+
+    // classical words only have their bases and the dictionary form,
+    // which is the terminal base
+    fn classical_conjugated(&self, form: Form) -> Option<String> {
+        let base = match form {
+            Form::DICTIONARY => return Some(self.dictionary_form.clone()),
+            Form::Classical(base) => base,
+            Form::ClassicalNegative | Form::ClassicalVolitional => Base::Irrealis,
+            _ => return None,
+        };
+        let based = [self.get_classical_stem(), self.classical_ending(base)?].join("");
+        match form {
+            Form::ClassicalNegative => Some([based, String::from(BUNGO_ZU)].join("")),
+            Form::ClassicalVolitional => Some([based, String::from(BUNGO_MU)].join("")),
+            _ => Some(based),
+        }
+    }
+
+    // e.g. か, き, く, くる, くれ, きよ for upper nidan verbs of the ka row
+    fn classical_ending(&self, base: Base) -> Option<String> {
+        let ending = match self.word_type.bungo()? {
+            Bungo::Yodan(row) => match base {
+                Base::Irrealis => String::from(row[0]),
+                Base::Continuative => String::from(row[1]),
+                Base::Terminal | Base::Attributive => String::from(row[2]),
+                Base::Realis | Base::Imperative => String::from(row[3]),
+            },
+            Bungo::UpperNidan(row) => self.nidan_ending(base, row[1], row[2]),
+            Bungo::LowerNidan(row) => self.nidan_ending(base, row[3], row[2]),
+            Bungo::Rahen => match base {
+                Base::Irrealis => String::from(RA_ROW[0]),
+                Base::Continuative | Base::Terminal => String::from(RA_ROW[1]),
+                Base::Attributive => String::from(RA_ROW[2]),
+                Base::Realis | Base::Imperative => String::from(RA_ROW[3]),
+            },
+            Bungo::Nahen => match base {
+                Base::Irrealis => String::from(NA_ROW[0]),
+                Base::Continuative => String::from(NA_ROW[1]),
+                Base::Terminal => String::from(NA_ROW[2]),
+                Base::Attributive => [NA_ROW[2], BUNGO_RU].join(""),
+                Base::Realis => [NA_ROW[2], BUNGO_RE].join(""),
+                Base::Imperative => String::from(NA_ROW[3]),
+            },
+            Bungo::Ku => self.adj_ku_ending(base),
+            Bungo::Shiku => match base {
+                Base::Terminal => String::from(ADJ_KU_END),
+                _ => [ADJ_KU_END, &self.adj_ku_ending(base)].join(""),
+            },
+        };
+        Some(ending)
+    }
+
+    // the vowel kana is i for upper and e for lower nidan verbs
+    fn nidan_ending(&self, base: Base, vowel: &str, u: &str) -> String {
+        match base {
+            Base::Irrealis | Base::Continuative => String::from(vowel),
+            Base::Terminal => String::from(u),
+            Base::Attributive => [u, BUNGO_RU].join(""),
+            Base::Realis => [u, BUNGO_RE].join(""),
+            Base::Imperative => [vowel, BUNGO_YO].join(""),
+        }
+    }
+
+    // the irrealis base is the kari form, e.g. 高から
+    fn adj_ku_ending(&self, base: Base) -> String {
+        let ending = match base {
+            Base::Irrealis => BUNGO_KARA,
+            Base::Continuative => BUNGO_KU,
+            Base::Terminal => ADJ_KU_END,
+            Base::Attributive => BUNGO_KI,
+            Base::Realis => BUNGO_KERE,
+            Base::Imperative => BUNGO_KARE,
+        };
+        String::from(ending)
+    }
+
+    // a word conjugated like the given word type, the reading is derived the same way
+    fn derived_entity(
+        &self,
+        dictionary_form: fn(&WordEntity) -> Option<String>,
+        word_type: WordType,
+    ) -> Option<WordEntity> {
+        Some(WordEntity {
+            dictionary_form: dictionary_form(self)?,
+            reading: dictionary_form(&self.reading_entity()).unwrap_or_default(),
+            translation: self.translation.clone(),
+            word_type,
+        })
+    }
+
+    // the stems are only taken from words ending the way the word type ends
+    fn check(&self) -> Result<(), ConjugationError> {
+        if self.dictionary_form.trim().is_empty() {
+            return Err(ConjugationError::EmptyWord);
+        }
+        // an auxiliary verb has to end like a verb
+        if self.word_type == WordType::AuxiliaryVerb
+            && !self.dictionary_form.ends_with(MASU)
+            && self.auxiliary_entity().is_none()
+        {
+            return Err(ConjugationError::WrongEnding(
+                self.dictionary_form.clone(),
+                self.word_type,
+            ));
+        }
+        let ending = match self.word_type.dictionary_ending() {
+            Some(ending) => ending,
+            None => return Ok(()),
+        };
+        for word in [&self.dictionary_form, &self.reading].iter() {
+            if !word.is_empty() && !word.ends_with(ending) {
+                return Err(ConjugationError::WrongEnding(
+                    (*word).clone(),
+                    self.word_type,
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn reading_entity(&self) -> WordEntity {
+        if self.reading.is_empty() {
+            return self.clone();
+        }
+        WordEntity {
+            dictionary_form: self.reading.clone(),
+            ..self.clone()
+        }
+    }
+
+    // stem
+    fn get_suru_stem(&self, word: String) -> String {
+        if word.ends_with(SURU) {
+            return self.trim_string(word, SURU);
+        }
+        word
+    }
+
+    // masu form without masu, e.g. kaki, tabe
+    fn get_masu_stem(&self) -> String {
+        match self.conjugated(Form::Indicative(NonPast, Affirmative, Polite)) {
+            Some(polite) => self.trim_string(polite, MASU),
+            None => String::new(),
+        }
+    }
+
+    fn get_zuru_stem(&self, word: String) -> String {
+        self.trim_string(word, ZURU)
+    }
+
+    fn get_kuru_stem(&self, word: String) -> String {
+        if word.ends_with(KURU_KANA) {
+            return self.trim_string(word, KURU_KANA);
+        }
+        self.trim_string(word, KURU)
+    }
+
+    fn get_godan_aru_stem(&self, word: String) -> String {
+        if word.ends_with(GODAN_ARU_SHA_END) {
+            return self.trim_string(word, GODAN_ARU_SHA_END);
+        }
+        if word.ends_with(GODAN_ARU_SA_END) {
+            return self.trim_string(word, GODAN_ARU_SA_END);
+        }
+        self.trim_string(word, GODAN_ARU_RU_END)
+    }
+
+    // nai form without nai, e.g. kaka, yoba
+    fn get_godan_a_row_stem(&self) -> String {
+        if self.word_type == WordType::VerbGodanRI {
+            let stem = self.get_godan_ri_stem(self.dictionary_form.clone());
+            if stem.is_empty() {
+                if self.dictionary_form.starts_with(ARU) {
+                    return [ARU_STEM, RA].join("");
+                }
+                return [ARU_STEM_KANA, RA].join("");
+            }
+            return [stem, String::from(RA)].join("");
+        }
+        self.trim_string(self.imperfective_negative_form(), NAI)
+    }
+
+    fn get_godan_b_stem(&self, word: String) -> String {
+        self.trim_string(word, GODAN_B_END)
     }
 
     fn get_godan_g_stem(&self, word: String) -> String {
@@ -619,7 +2465,34 @@ impl WordEntity {
         if stem != I_KANA {
             return stem;
         }
-        return String::from(ADJ_YOI_KANA);
+        String::from(ADJ_YOI_KANA)
+    }
+
+    fn get_classical_stem(&self) -> String {
+        match self.word_type.dictionary_ending() {
+            Some(ending) => self.trim_string(self.dictionary_form.clone(), ending),
+            None => self.dictionary_form.clone(),
+        }
+    }
+
+    fn get_to_stem(&self, word: String) -> String {
+        if word.ends_with(TARU) {
+            return self.trim_string(word, TARU);
+        }
+        if word.ends_with(TO) {
+            return self.trim_string(word, TO);
+        }
+        word
+    }
+
+    fn get_copula_stem(&self, word: String) -> String {
+        if word.ends_with(DESU) {
+            return self.trim_string(word, DESU);
+        }
+        if word.ends_with(DA) {
+            return self.trim_string(word, DA);
+        }
+        word
     }
 
     fn get_adj_na_stem(&self, word: String) -> String {
@@ -632,12 +2505,13 @@ impl WordEntity {
     fn trim_string(&self, word: String, word_ending: &str) -> String {
         let word_length: usize = word.chars().count();
         let ending_length: usize = word_ending.chars().count();
-        word[..word
+        match word
             .char_indices()
-            .nth(word_length - ending_length)
-            .unwrap()
-            .0]
-            .to_string()
+            .nth(word_length.saturating_sub(ending_length))
+        {
+            Some((index, _)) => word[..index].to_string(),
+            None => word,
+        }
     }
 }
 
@@ -664,6 +2538,35 @@ mod tests {
         formal_potential_negative_form: String,
         formal_perfective_potential_form: String,
         formal_perfective_potential_negative_form: String,
+        informal_imperative_form: String,
+        informal_imperative_negative_form: String,
+        formal_imperative_form: String,
+        formal_imperative_negative_form: String,
+        written_imperative_form: String,
+        volitional_form: String,
+        volitional_negative_form: String,
+        formal_volitional_form: String,
+        volitional_negative_variant_form: String,
+        te_form: String,
+        negative_te_form: String,
+        negative_te_variant_form: String,
+        progressive_form: String,
+        progressive_negative_form: String,
+        perfective_progressive_form: String,
+        perfective_progressive_negative_form: String,
+        formal_progressive_form: String,
+        formal_progressive_negative_form: String,
+        formal_perfective_progressive_form: String,
+        formal_perfective_progressive_negative_form: String,
+        provisional_form: String,
+        provisional_negative_form: String,
+        conditional_form: String,
+        conditional_negative_form: String,
+        hypothetical_form: String,
+        hypothetical_negative_form: String,
+        formal_volitional_negative_form: String,
+        formal_imperfective_negative_variant_form: String,
+        formal_perfective_negative_variant_form: String,
     }
 
     lazy_static! {
@@ -671,6 +2574,7 @@ mod tests {
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from(SURU),
+                    reading: String::from(SURU),
                     translation: String::from("to do"),
                     word_type: WordType::VerbSuru,
                 },
@@ -690,10 +2594,40 @@ mod tests {
                 formal_potential_negative_form: String::from("できません"),
                 formal_perfective_potential_form: String::from("できました"),
                 formal_perfective_potential_negative_form: String::from("できませんでした"),
+                informal_imperative_form: String::from("しろ"),
+                informal_imperative_negative_form: String::from("するな"),
+                formal_imperative_form: String::from("しなさい"),
+                formal_imperative_negative_form: String::from("しないでください"),
+                written_imperative_form: String::from("せよ"),
+                volitional_form: String::from("しよう"),
+                volitional_negative_form: String::from("するまい"),
+                formal_volitional_form: String::from("しましょう"),
+                volitional_negative_variant_form: String::from("しまい"),
+                te_form: String::from("して"),
+                negative_te_form: String::from("しないで"),
+                negative_te_variant_form: String::from("しなくて"),
+                progressive_form: String::from("している"),
+                progressive_negative_form: String::from("していない"),
+                perfective_progressive_form: String::from("していた"),
+                perfective_progressive_negative_form: String::from("していなかった"),
+                formal_progressive_form: String::from("しています"),
+                formal_progressive_negative_form: String::from("していません"),
+                formal_perfective_progressive_form: String::from("していました"),
+                formal_perfective_progressive_negative_form: String::from("していませんでした"),
+                provisional_form: String::from("すれば"),
+                provisional_negative_form: String::from("しなければ"),
+                conditional_form: String::from("したら"),
+                conditional_negative_form: String::from("しなかったら"),
+                hypothetical_form: String::from("するなら"),
+                hypothetical_negative_form: String::from("しないなら"),
+                formal_volitional_negative_form: String::from("しますまい"),
+                formal_imperfective_negative_variant_form: String::from("しないです"),
+                formal_perfective_negative_variant_form: String::from("しなかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("準備する"),
+                    reading: String::from("じゅんびする"),
                     translation: String::from("to prepare"),
                     word_type: WordType::VerbSuru,
                 },
@@ -715,10 +2649,40 @@ mod tests {
                 formal_potential_negative_form: String::from("準備できません"),
                 formal_perfective_potential_form: String::from("準備できました"),
                 formal_perfective_potential_negative_form: String::from("準備できませんでした"),
+                informal_imperative_form: String::from("準備しろ"),
+                informal_imperative_negative_form: String::from("準備するな"),
+                formal_imperative_form: String::from("準備しなさい"),
+                formal_imperative_negative_form: String::from("準備しないでください"),
+                written_imperative_form: String::from("準備せよ"),
+                volitional_form: String::from("準備しよう"),
+                volitional_negative_form: String::from("準備するまい"),
+                formal_volitional_form: String::from("準備しましょう"),
+                volitional_negative_variant_form: String::from("準備しまい"),
+                te_form: String::from("準備して"),
+                negative_te_form: String::from("準備しないで"),
+                negative_te_variant_form: String::from("準備しなくて"),
+                progressive_form: String::from("準備している"),
+                progressive_negative_form: String::from("準備していない"),
+                perfective_progressive_form: String::from("準備していた"),
+                perfective_progressive_negative_form: String::from("準備していなかった"),
+                formal_progressive_form: String::from("準備しています"),
+                formal_progressive_negative_form: String::from("準備していません"),
+                formal_perfective_progressive_form: String::from("準備していました"),
+                formal_perfective_progressive_negative_form: String::from("準備していませんでした"),
+                provisional_form: String::from("準備すれば"),
+                provisional_negative_form: String::from("準備しなければ"),
+                conditional_form: String::from("準備したら"),
+                conditional_negative_form: String::from("準備しなかったら"),
+                hypothetical_form: String::from("準備するなら"),
+                hypothetical_negative_form: String::from("準備しないなら"),
+                formal_volitional_negative_form: String::from("準備しますまい"),
+                formal_imperfective_negative_variant_form: String::from("準備しないです"),
+                formal_perfective_negative_variant_form: String::from("準備しなかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("来る"),
+                    reading: String::from(KURU_KANA),
                     translation: String::from("to prepare"),
                     word_type: WordType::VerbKuru,
                 },
@@ -738,10 +2702,40 @@ mod tests {
                 formal_potential_negative_form: String::from("こられません"),
                 formal_perfective_potential_form: String::from("こられました"),
                 formal_perfective_potential_negative_form: String::from("こられませんでした"),
+                informal_imperative_form: String::from("こい"),
+                informal_imperative_negative_form: String::from("くるな"),
+                formal_imperative_form: String::from("きなさい"),
+                formal_imperative_negative_form: String::from("こないでください"),
+                written_imperative_form: String::from("こよ"),
+                volitional_form: String::from("こよう"),
+                volitional_negative_form: String::from("くるまい"),
+                formal_volitional_form: String::from("きましょう"),
+                volitional_negative_variant_form: String::from("こまい"),
+                te_form: String::from("きて"),
+                negative_te_form: String::from("こないで"),
+                negative_te_variant_form: String::from("こなくて"),
+                progressive_form: String::from("きている"),
+                progressive_negative_form: String::from("きていない"),
+                perfective_progressive_form: String::from("きていた"),
+                perfective_progressive_negative_form: String::from("きていなかった"),
+                formal_progressive_form: String::from("きています"),
+                formal_progressive_negative_form: String::from("きていません"),
+                formal_perfective_progressive_form: String::from("きていました"),
+                formal_perfective_progressive_negative_form: String::from("きていませんでした"),
+                provisional_form: String::from("くれば"),
+                provisional_negative_form: String::from("こなければ"),
+                conditional_form: String::from("きたら"),
+                conditional_negative_form: String::from("こなかったら"),
+                hypothetical_form: String::from("くるなら"),
+                hypothetical_negative_form: String::from("こないなら"),
+                formal_volitional_negative_form: String::from("きますまい"),
+                formal_imperfective_negative_variant_form: String::from("こないです"),
+                formal_perfective_negative_variant_form: String::from("こなかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("くる"),
+                    reading: String::from(KURU_KANA),
                     translation: String::from("to prepare"),
                     word_type: WordType::VerbKuru,
                 },
@@ -761,10 +2755,40 @@ mod tests {
                 formal_potential_negative_form: String::from("こられません"),
                 formal_perfective_potential_form: String::from("こられました"),
                 formal_perfective_potential_negative_form: String::from("こられませんでした"),
+                informal_imperative_form: String::from("こい"),
+                informal_imperative_negative_form: String::from("くるな"),
+                formal_imperative_form: String::from("きなさい"),
+                formal_imperative_negative_form: String::from("こないでください"),
+                written_imperative_form: String::from("こよ"),
+                volitional_form: String::from("こよう"),
+                volitional_negative_form: String::from("くるまい"),
+                formal_volitional_form: String::from("きましょう"),
+                volitional_negative_variant_form: String::from("こまい"),
+                te_form: String::from("きて"),
+                negative_te_form: String::from("こないで"),
+                negative_te_variant_form: String::from("こなくて"),
+                progressive_form: String::from("きている"),
+                progressive_negative_form: String::from("きていない"),
+                perfective_progressive_form: String::from("きていた"),
+                perfective_progressive_negative_form: String::from("きていなかった"),
+                formal_progressive_form: String::from("きています"),
+                formal_progressive_negative_form: String::from("きていません"),
+                formal_perfective_progressive_form: String::from("きていました"),
+                formal_perfective_progressive_negative_form: String::from("きていませんでした"),
+                provisional_form: String::from("くれば"),
+                provisional_negative_form: String::from("こなければ"),
+                conditional_form: String::from("きたら"),
+                conditional_negative_form: String::from("こなかったら"),
+                hypothetical_form: String::from("くるなら"),
+                hypothetical_negative_form: String::from("こないなら"),
+                formal_volitional_negative_form: String::from("きますまい"),
+                formal_imperfective_negative_variant_form: String::from("こないです"),
+                formal_perfective_negative_variant_form: String::from("こなかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("下さる"),
+                    reading: String::from("くださる"),
                     translation: String::from("to give"),
                     word_type: WordType::VerbGodanARU,
                 },
@@ -784,10 +2808,40 @@ mod tests {
                 formal_potential_negative_form: String::from("下されません"),
                 formal_perfective_potential_form: String::from("下されました"),
                 formal_perfective_potential_negative_form: String::from("下されませんでした"),
+                informal_imperative_form: String::from("下さい"),
+                informal_imperative_negative_form: String::from("下さるな"),
+                formal_imperative_form: String::from("下さいませ"),
+                formal_imperative_negative_form: String::from("下さらないでください"),
+                written_imperative_form: String::from("下さい"),
+                volitional_form: String::from("下さろう"),
+                volitional_negative_form: String::from("下さるまい"),
+                formal_volitional_form: String::from("下さいましょう"),
+                volitional_negative_variant_form: String::from("下さるまい"),
+                te_form: String::from("下さって"),
+                negative_te_form: String::from("下さらないで"),
+                negative_te_variant_form: String::from("下さらなくて"),
+                progressive_form: String::from("下さっている"),
+                progressive_negative_form: String::from("下さっていない"),
+                perfective_progressive_form: String::from("下さっていた"),
+                perfective_progressive_negative_form: String::from("下さっていなかった"),
+                formal_progressive_form: String::from("下さっています"),
+                formal_progressive_negative_form: String::from("下さっていません"),
+                formal_perfective_progressive_form: String::from("下さっていました"),
+                formal_perfective_progressive_negative_form: String::from("下さっていませんでした"),
+                provisional_form: String::from("下されば"),
+                provisional_negative_form: String::from("下さらなければ"),
+                conditional_form: String::from("下さったら"),
+                conditional_negative_form: String::from("下さらなかったら"),
+                hypothetical_form: String::from("下さるなら"),
+                hypothetical_negative_form: String::from("下さらないなら"),
+                formal_volitional_negative_form: String::from("下さいますまい"),
+                formal_imperfective_negative_variant_form: String::from("下さらないです"),
+                formal_perfective_negative_variant_form: String::from("下さらなかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("いらっしゃる"),
+                    reading: String::from("いらっしゃる"),
                     translation: String::from("to go"),
                     word_type: WordType::VerbGodanARU,
                 },
@@ -811,10 +2865,42 @@ mod tests {
                 formal_potential_negative_form: String::from("いらっしゃれません"),
                 formal_perfective_potential_form: String::from("いらっしゃれました"),
                 formal_perfective_potential_negative_form: String::from("いらっしゃれませんでした"),
+                informal_imperative_form: String::from("いらっしゃい"),
+                informal_imperative_negative_form: String::from("いらっしゃるな"),
+                formal_imperative_form: String::from("いらっしゃいませ"),
+                formal_imperative_negative_form: String::from("いらっしゃらないでください"),
+                written_imperative_form: String::from("いらっしゃい"),
+                volitional_form: String::from("いらっしゃろう"),
+                volitional_negative_form: String::from("いらっしゃるまい"),
+                formal_volitional_form: String::from("いらっしゃいましょう"),
+                volitional_negative_variant_form: String::from("いらっしゃるまい"),
+                te_form: String::from("いらっしゃって"),
+                negative_te_form: String::from("いらっしゃらないで"),
+                negative_te_variant_form: String::from("いらっしゃらなくて"),
+                progressive_form: String::from("いらっしゃっている"),
+                progressive_negative_form: String::from("いらっしゃっていない"),
+                perfective_progressive_form: String::from("いらっしゃっていた"),
+                perfective_progressive_negative_form: String::from("いらっしゃっていなかった"),
+                formal_progressive_form: String::from("いらっしゃっています"),
+                formal_progressive_negative_form: String::from("いらっしゃっていません"),
+                formal_perfective_progressive_form: String::from("いらっしゃっていました"),
+                formal_perfective_progressive_negative_form: String::from(
+                    "いらっしゃっていませんでした"
+                ),
+                provisional_form: String::from("いらっしゃれば"),
+                provisional_negative_form: String::from("いらっしゃらなければ"),
+                conditional_form: String::from("いらっしゃったら"),
+                conditional_negative_form: String::from("いらっしゃらなかったら"),
+                hypothetical_form: String::from("いらっしゃるなら"),
+                hypothetical_negative_form: String::from("いらっしゃらないなら"),
+                formal_volitional_negative_form: String::from("いらっしゃいますまい"),
+                formal_imperfective_negative_variant_form: String::from("いらっしゃらないです"),
+                formal_perfective_negative_variant_form: String::from("いらっしゃらなかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("呼ぶ"),
+                    reading: String::from("よぶ"),
                     translation: String::from("to call"),
                     word_type: WordType::VerbGodanB,
                 },
@@ -834,10 +2920,40 @@ mod tests {
                 formal_potential_negative_form: String::from("呼べません"),
                 formal_perfective_potential_form: String::from("呼べました"),
                 formal_perfective_potential_negative_form: String::from("呼べませんでした"),
+                informal_imperative_form: String::from("呼べ"),
+                informal_imperative_negative_form: String::from("呼ぶな"),
+                formal_imperative_form: String::from("呼びなさい"),
+                formal_imperative_negative_form: String::from("呼ばないでください"),
+                written_imperative_form: String::from("呼べ"),
+                volitional_form: String::from("呼ぼう"),
+                volitional_negative_form: String::from("呼ぶまい"),
+                formal_volitional_form: String::from("呼びましょう"),
+                volitional_negative_variant_form: String::from("呼ぶまい"),
+                te_form: String::from("呼んで"),
+                negative_te_form: String::from("呼ばないで"),
+                negative_te_variant_form: String::from("呼ばなくて"),
+                progressive_form: String::from("呼んでいる"),
+                progressive_negative_form: String::from("呼んでいない"),
+                perfective_progressive_form: String::from("呼んでいた"),
+                perfective_progressive_negative_form: String::from("呼んでいなかった"),
+                formal_progressive_form: String::from("呼んでいます"),
+                formal_progressive_negative_form: String::from("呼んでいません"),
+                formal_perfective_progressive_form: String::from("呼んでいました"),
+                formal_perfective_progressive_negative_form: String::from("呼んでいませんでした"),
+                provisional_form: String::from("呼べば"),
+                provisional_negative_form: String::from("呼ばなければ"),
+                conditional_form: String::from("呼んだら"),
+                conditional_negative_form: String::from("呼ばなかったら"),
+                hypothetical_form: String::from("呼ぶなら"),
+                hypothetical_negative_form: String::from("呼ばないなら"),
+                formal_volitional_negative_form: String::from("呼びますまい"),
+                formal_imperfective_negative_variant_form: String::from("呼ばないです"),
+                formal_perfective_negative_variant_form: String::from("呼ばなかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("泳ぐ"),
+                    reading: String::from("およぐ"),
                     translation: String::from("to swim"),
                     word_type: WordType::VerbGodanG,
                 },
@@ -857,10 +2973,40 @@ mod tests {
                 formal_potential_negative_form: String::from("泳げません"),
                 formal_perfective_potential_form: String::from("泳げました"),
                 formal_perfective_potential_negative_form: String::from("泳げませんでした"),
+                informal_imperative_form: String::from("泳げ"),
+                informal_imperative_negative_form: String::from("泳ぐな"),
+                formal_imperative_form: String::from("泳ぎなさい"),
+                formal_imperative_negative_form: String::from("泳がないでください"),
+                written_imperative_form: String::from("泳げ"),
+                volitional_form: String::from("泳ごう"),
+                volitional_negative_form: String::from("泳ぐまい"),
+                formal_volitional_form: String::from("泳ぎましょう"),
+                volitional_negative_variant_form: String::from("泳ぐまい"),
+                te_form: String::from("泳いで"),
+                negative_te_form: String::from("泳がないで"),
+                negative_te_variant_form: String::from("泳がなくて"),
+                progressive_form: String::from("泳いでいる"),
+                progressive_negative_form: String::from("泳いでいない"),
+                perfective_progressive_form: String::from("泳いでいた"),
+                perfective_progressive_negative_form: String::from("泳いでいなかった"),
+                formal_progressive_form: String::from("泳いでいます"),
+                formal_progressive_negative_form: String::from("泳いでいません"),
+                formal_perfective_progressive_form: String::from("泳いでいました"),
+                formal_perfective_progressive_negative_form: String::from("泳いでいませんでした"),
+                provisional_form: String::from("泳げば"),
+                provisional_negative_form: String::from("泳がなければ"),
+                conditional_form: String::from("泳いだら"),
+                conditional_negative_form: String::from("泳がなかったら"),
+                hypothetical_form: String::from("泳ぐなら"),
+                hypothetical_negative_form: String::from("泳がないなら"),
+                formal_volitional_negative_form: String::from("泳ぎますまい"),
+                formal_imperfective_negative_variant_form: String::from("泳がないです"),
+                formal_perfective_negative_variant_form: String::from("泳がなかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("焼く"),
+                    reading: String::from("やく"),
                     translation: String::from("to grill"),
                     word_type: WordType::VerbGodanK,
                 },
@@ -880,10 +3026,40 @@ mod tests {
                 formal_potential_negative_form: String::from("焼けません"),
                 formal_perfective_potential_form: String::from("焼けました"),
                 formal_perfective_potential_negative_form: String::from("焼けませんでした"),
+                informal_imperative_form: String::from("焼け"),
+                informal_imperative_negative_form: String::from("焼くな"),
+                formal_imperative_form: String::from("焼きなさい"),
+                formal_imperative_negative_form: String::from("焼かないでください"),
+                written_imperative_form: String::from("焼け"),
+                volitional_form: String::from("焼こう"),
+                volitional_negative_form: String::from("焼くまい"),
+                formal_volitional_form: String::from("焼きましょう"),
+                volitional_negative_variant_form: String::from("焼くまい"),
+                te_form: String::from("焼いて"),
+                negative_te_form: String::from("焼かないで"),
+                negative_te_variant_form: String::from("焼かなくて"),
+                progressive_form: String::from("焼いている"),
+                progressive_negative_form: String::from("焼いていない"),
+                perfective_progressive_form: String::from("焼いていた"),
+                perfective_progressive_negative_form: String::from("焼いていなかった"),
+                formal_progressive_form: String::from("焼いています"),
+                formal_progressive_negative_form: String::from("焼いていません"),
+                formal_perfective_progressive_form: String::from("焼いていました"),
+                formal_perfective_progressive_negative_form: String::from("焼いていませんでした"),
+                provisional_form: String::from("焼けば"),
+                provisional_negative_form: String::from("焼かなければ"),
+                conditional_form: String::from("焼いたら"),
+                conditional_negative_form: String::from("焼かなかったら"),
+                hypothetical_form: String::from("焼くなら"),
+                hypothetical_negative_form: String::from("焼かないなら"),
+                formal_volitional_negative_form: String::from("焼きますまい"),
+                formal_imperfective_negative_variant_form: String::from("焼かないです"),
+                formal_perfective_negative_variant_form: String::from("焼かなかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("行く"),
+                    reading: String::from("いく"),
                     translation: String::from("to go"),
                     word_type: WordType::VerbGodanKS,
                 },
@@ -903,10 +3079,40 @@ mod tests {
                 formal_potential_negative_form: String::from("行けません"),
                 formal_perfective_potential_form: String::from("行けました"),
                 formal_perfective_potential_negative_form: String::from("行けませんでした"),
+                informal_imperative_form: String::from("行け"),
+                informal_imperative_negative_form: String::from("行くな"),
+                formal_imperative_form: String::from("行きなさい"),
+                formal_imperative_negative_form: String::from("行かないでください"),
+                written_imperative_form: String::from("行け"),
+                volitional_form: String::from("行こう"),
+                volitional_negative_form: String::from("行くまい"),
+                formal_volitional_form: String::from("行きましょう"),
+                volitional_negative_variant_form: String::from("行くまい"),
+                te_form: String::from("行って"),
+                negative_te_form: String::from("行かないで"),
+                negative_te_variant_form: String::from("行かなくて"),
+                progressive_form: String::from("行っている"),
+                progressive_negative_form: String::from("行っていない"),
+                perfective_progressive_form: String::from("行っていた"),
+                perfective_progressive_negative_form: String::from("行っていなかった"),
+                formal_progressive_form: String::from("行っています"),
+                formal_progressive_negative_form: String::from("行っていません"),
+                formal_perfective_progressive_form: String::from("行っていました"),
+                formal_perfective_progressive_negative_form: String::from("行っていませんでした"),
+                provisional_form: String::from("行けば"),
+                provisional_negative_form: String::from("行かなければ"),
+                conditional_form: String::from("行ったら"),
+                conditional_negative_form: String::from("行かなかったら"),
+                hypothetical_form: String::from("行くなら"),
+                hypothetical_negative_form: String::from("行かないなら"),
+                formal_volitional_negative_form: String::from("行きますまい"),
+                formal_imperfective_negative_variant_form: String::from("行かないです"),
+                formal_perfective_negative_variant_form: String::from("行かなかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("読む"),
+                    reading: String::from("よむ"),
                     translation: String::from("to read"),
                     word_type: WordType::VerbGodanM,
                 },
@@ -926,10 +3132,40 @@ mod tests {
                 formal_potential_negative_form: String::from("読めません"),
                 formal_perfective_potential_form: String::from("読めました"),
                 formal_perfective_potential_negative_form: String::from("読めませんでした"),
+                informal_imperative_form: String::from("読め"),
+                informal_imperative_negative_form: String::from("読むな"),
+                formal_imperative_form: String::from("読みなさい"),
+                formal_imperative_negative_form: String::from("読まないでください"),
+                written_imperative_form: String::from("読め"),
+                volitional_form: String::from("読もう"),
+                volitional_negative_form: String::from("読むまい"),
+                formal_volitional_form: String::from("読みましょう"),
+                volitional_negative_variant_form: String::from("読むまい"),
+                te_form: String::from("読んで"),
+                negative_te_form: String::from("読まないで"),
+                negative_te_variant_form: String::from("読まなくて"),
+                progressive_form: String::from("読んでいる"),
+                progressive_negative_form: String::from("読んでいない"),
+                perfective_progressive_form: String::from("読んでいた"),
+                perfective_progressive_negative_form: String::from("読んでいなかった"),
+                formal_progressive_form: String::from("読んでいます"),
+                formal_progressive_negative_form: String::from("読んでいません"),
+                formal_perfective_progressive_form: String::from("読んでいました"),
+                formal_perfective_progressive_negative_form: String::from("読んでいませんでした"),
+                provisional_form: String::from("読めば"),
+                provisional_negative_form: String::from("読まなければ"),
+                conditional_form: String::from("読んだら"),
+                conditional_negative_form: String::from("読まなかったら"),
+                hypothetical_form: String::from("読むなら"),
+                hypothetical_negative_form: String::from("読まないなら"),
+                formal_volitional_negative_form: String::from("読みますまい"),
+                formal_imperfective_negative_variant_form: String::from("読まないです"),
+                formal_perfective_negative_variant_form: String::from("読まなかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("死ぬ"),
+                    reading: String::from("しぬ"),
                     translation: String::from("to die"),
                     word_type: WordType::VerbGodanN,
                 },
@@ -949,10 +3185,40 @@ mod tests {
                 formal_potential_negative_form: String::from("死ねません"),
                 formal_perfective_potential_form: String::from("死ねました"),
                 formal_perfective_potential_negative_form: String::from("死ねませんでした"),
+                informal_imperative_form: String::from("死ね"),
+                informal_imperative_negative_form: String::from("死ぬな"),
+                formal_imperative_form: String::from("死になさい"),
+                formal_imperative_negative_form: String::from("死なないでください"),
+                written_imperative_form: String::from("死ね"),
+                volitional_form: String::from("死のう"),
+                volitional_negative_form: String::from("死ぬまい"),
+                formal_volitional_form: String::from("死にましょう"),
+                volitional_negative_variant_form: String::from("死ぬまい"),
+                te_form: String::from("死んで"),
+                negative_te_form: String::from("死なないで"),
+                negative_te_variant_form: String::from("死ななくて"),
+                progressive_form: String::from("死んでいる"),
+                progressive_negative_form: String::from("死んでいない"),
+                perfective_progressive_form: String::from("死んでいた"),
+                perfective_progressive_negative_form: String::from("死んでいなかった"),
+                formal_progressive_form: String::from("死んでいます"),
+                formal_progressive_negative_form: String::from("死んでいません"),
+                formal_perfective_progressive_form: String::from("死んでいました"),
+                formal_perfective_progressive_negative_form: String::from("死んでいませんでした"),
+                provisional_form: String::from("死ねば"),
+                provisional_negative_form: String::from("死ななければ"),
+                conditional_form: String::from("死んだら"),
+                conditional_negative_form: String::from("死ななかったら"),
+                hypothetical_form: String::from("死ぬなら"),
+                hypothetical_negative_form: String::from("死なないなら"),
+                formal_volitional_negative_form: String::from("死にますまい"),
+                formal_imperfective_negative_variant_form: String::from("死なないです"),
+                formal_perfective_negative_variant_form: String::from("死ななかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("走る"),
+                    reading: String::from("はしる"),
                     translation: String::from("to run"),
                     word_type: WordType::VerbGodanR,
                 },
@@ -972,10 +3238,40 @@ mod tests {
                 formal_potential_negative_form: String::from("走れません"),
                 formal_perfective_potential_form: String::from("走れました"),
                 formal_perfective_potential_negative_form: String::from("走れませんでした"),
+                informal_imperative_form: String::from("走れ"),
+                informal_imperative_negative_form: String::from("走るな"),
+                formal_imperative_form: String::from("走りなさい"),
+                formal_imperative_negative_form: String::from("走らないでください"),
+                written_imperative_form: String::from("走れ"),
+                volitional_form: String::from("走ろう"),
+                volitional_negative_form: String::from("走るまい"),
+                formal_volitional_form: String::from("走りましょう"),
+                volitional_negative_variant_form: String::from("走るまい"),
+                te_form: String::from("走って"),
+                negative_te_form: String::from("走らないで"),
+                negative_te_variant_form: String::from("走らなくて"),
+                progressive_form: String::from("走っている"),
+                progressive_negative_form: String::from("走っていない"),
+                perfective_progressive_form: String::from("走っていた"),
+                perfective_progressive_negative_form: String::from("走っていなかった"),
+                formal_progressive_form: String::from("走っています"),
+                formal_progressive_negative_form: String::from("走っていません"),
+                formal_perfective_progressive_form: String::from("走っていました"),
+                formal_perfective_progressive_negative_form: String::from("走っていませんでした"),
+                provisional_form: String::from("走れば"),
+                provisional_negative_form: String::from("走らなければ"),
+                conditional_form: String::from("走ったら"),
+                conditional_negative_form: String::from("走らなかったら"),
+                hypothetical_form: String::from("走るなら"),
+                hypothetical_negative_form: String::from("走らないなら"),
+                formal_volitional_negative_form: String::from("走りますまい"),
+                formal_imperfective_negative_variant_form: String::from("走らないです"),
+                formal_perfective_negative_variant_form: String::from("走らなかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("有る"),
+                    reading: String::from(ARU_KANA),
                     translation: String::from("to exist"),
                     word_type: WordType::VerbGodanRI,
                 },
@@ -995,10 +3291,40 @@ mod tests {
                 formal_potential_negative_form: String::from("有れません"),
                 formal_perfective_potential_form: String::from("有れました"),
                 formal_perfective_potential_negative_form: String::from("有れませんでした"),
+                informal_imperative_form: String::from("有れ"),
+                informal_imperative_negative_form: String::from("有るな"),
+                formal_imperative_form: String::from("有りなさい"),
+                formal_imperative_negative_form: String::from("ないでください"),
+                written_imperative_form: String::from("有れ"),
+                volitional_form: String::from("有ろう"),
+                volitional_negative_form: String::from("有るまい"),
+                formal_volitional_form: String::from("有りましょう"),
+                volitional_negative_variant_form: String::from("有るまい"),
+                te_form: String::from("有って"),
+                negative_te_form: String::from("ないで"),
+                negative_te_variant_form: String::from("なくて"),
+                progressive_form: String::from(NOT_APPLICABLE),
+                progressive_negative_form: String::from(NOT_APPLICABLE),
+                perfective_progressive_form: String::from(NOT_APPLICABLE),
+                perfective_progressive_negative_form: String::from(NOT_APPLICABLE),
+                formal_progressive_form: String::from(NOT_APPLICABLE),
+                formal_progressive_negative_form: String::from(NOT_APPLICABLE),
+                formal_perfective_progressive_form: String::from(NOT_APPLICABLE),
+                formal_perfective_progressive_negative_form: String::from(NOT_APPLICABLE),
+                provisional_form: String::from("有れば"),
+                provisional_negative_form: String::from("なければ"),
+                conditional_form: String::from("有ったら"),
+                conditional_negative_form: String::from("なかったら"),
+                hypothetical_form: String::from("有るなら"),
+                hypothetical_negative_form: String::from("ないなら"),
+                formal_volitional_negative_form: String::from("有りますまい"),
+                formal_imperfective_negative_variant_form: String::from("ないです"),
+                formal_perfective_negative_variant_form: String::from("なかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("ある"),
+                    reading: String::from(ARU_KANA),
                     translation: String::from("to show"),
                     word_type: WordType::VerbGodanRI,
                 },
@@ -1018,10 +3344,40 @@ mod tests {
                 formal_potential_negative_form: String::from("あれません"),
                 formal_perfective_potential_form: String::from("あれました"),
                 formal_perfective_potential_negative_form: String::from("あれませんでした"),
+                informal_imperative_form: String::from("あれ"),
+                informal_imperative_negative_form: String::from("あるな"),
+                formal_imperative_form: String::from("ありなさい"),
+                formal_imperative_negative_form: String::from("ないでください"),
+                written_imperative_form: String::from("あれ"),
+                volitional_form: String::from("あろう"),
+                volitional_negative_form: String::from("あるまい"),
+                formal_volitional_form: String::from("ありましょう"),
+                volitional_negative_variant_form: String::from("あるまい"),
+                te_form: String::from("あって"),
+                negative_te_form: String::from("ないで"),
+                negative_te_variant_form: String::from("なくて"),
+                progressive_form: String::from(NOT_APPLICABLE),
+                progressive_negative_form: String::from(NOT_APPLICABLE),
+                perfective_progressive_form: String::from(NOT_APPLICABLE),
+                perfective_progressive_negative_form: String::from(NOT_APPLICABLE),
+                formal_progressive_form: String::from(NOT_APPLICABLE),
+                formal_progressive_negative_form: String::from(NOT_APPLICABLE),
+                formal_perfective_progressive_form: String::from(NOT_APPLICABLE),
+                formal_perfective_progressive_negative_form: String::from(NOT_APPLICABLE),
+                provisional_form: String::from("あれば"),
+                provisional_negative_form: String::from("なければ"),
+                conditional_form: String::from("あったら"),
+                conditional_negative_form: String::from("なかったら"),
+                hypothetical_form: String::from("あるなら"),
+                hypothetical_negative_form: String::from("ないなら"),
+                formal_volitional_negative_form: String::from("ありますまい"),
+                formal_imperfective_negative_variant_form: String::from("ないです"),
+                formal_perfective_negative_variant_form: String::from("なかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("示す"),
+                    reading: String::from("しめす"),
                     translation: String::from("to show"),
                     word_type: WordType::VerbGodanS,
                 },
@@ -1041,10 +3397,40 @@ mod tests {
                 formal_potential_negative_form: String::from("示せません"),
                 formal_perfective_potential_form: String::from("示せました"),
                 formal_perfective_potential_negative_form: String::from("示せませんでした"),
+                informal_imperative_form: String::from("示せ"),
+                informal_imperative_negative_form: String::from("示すな"),
+                formal_imperative_form: String::from("示しなさい"),
+                formal_imperative_negative_form: String::from("示さないでください"),
+                written_imperative_form: String::from("示せ"),
+                volitional_form: String::from("示そう"),
+                volitional_negative_form: String::from("示すまい"),
+                formal_volitional_form: String::from("示しましょう"),
+                volitional_negative_variant_form: String::from("示すまい"),
+                te_form: String::from("示して"),
+                negative_te_form: String::from("示さないで"),
+                negative_te_variant_form: String::from("示さなくて"),
+                progressive_form: String::from("示している"),
+                progressive_negative_form: String::from("示していない"),
+                perfective_progressive_form: String::from("示していた"),
+                perfective_progressive_negative_form: String::from("示していなかった"),
+                formal_progressive_form: String::from("示しています"),
+                formal_progressive_negative_form: String::from("示していません"),
+                formal_perfective_progressive_form: String::from("示していました"),
+                formal_perfective_progressive_negative_form: String::from("示していませんでした"),
+                provisional_form: String::from("示せば"),
+                provisional_negative_form: String::from("示さなければ"),
+                conditional_form: String::from("示したら"),
+                conditional_negative_form: String::from("示さなかったら"),
+                hypothetical_form: String::from("示すなら"),
+                hypothetical_negative_form: String::from("示さないなら"),
+                formal_volitional_negative_form: String::from("示しますまい"),
+                formal_imperfective_negative_variant_form: String::from("示さないです"),
+                formal_perfective_negative_variant_form: String::from("示さなかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("待つ"),
+                    reading: String::from("まつ"),
                     translation: String::from("to wait"),
                     word_type: WordType::VerbGodanT,
                 },
@@ -1064,10 +3450,40 @@ mod tests {
                 formal_potential_negative_form: String::from("待てません"),
                 formal_perfective_potential_form: String::from("待てました"),
                 formal_perfective_potential_negative_form: String::from("待てませんでした"),
+                informal_imperative_form: String::from("待て"),
+                informal_imperative_negative_form: String::from("待つな"),
+                formal_imperative_form: String::from("待ちなさい"),
+                formal_imperative_negative_form: String::from("待たないでください"),
+                written_imperative_form: String::from("待て"),
+                volitional_form: String::from("待とう"),
+                volitional_negative_form: String::from("待つまい"),
+                formal_volitional_form: String::from("待ちましょう"),
+                volitional_negative_variant_form: String::from("待つまい"),
+                te_form: String::from("待って"),
+                negative_te_form: String::from("待たないで"),
+                negative_te_variant_form: String::from("待たなくて"),
+                progressive_form: String::from("待っている"),
+                progressive_negative_form: String::from("待っていない"),
+                perfective_progressive_form: String::from("待っていた"),
+                perfective_progressive_negative_form: String::from("待っていなかった"),
+                formal_progressive_form: String::from("待っています"),
+                formal_progressive_negative_form: String::from("待っていません"),
+                formal_perfective_progressive_form: String::from("待っていました"),
+                formal_perfective_progressive_negative_form: String::from("待っていませんでした"),
+                provisional_form: String::from("待てば"),
+                provisional_negative_form: String::from("待たなければ"),
+                conditional_form: String::from("待ったら"),
+                conditional_negative_form: String::from("待たなかったら"),
+                hypothetical_form: String::from("待つなら"),
+                hypothetical_negative_form: String::from("待たないなら"),
+                formal_volitional_negative_form: String::from("待ちますまい"),
+                formal_imperfective_negative_variant_form: String::from("待たないです"),
+                formal_perfective_negative_variant_form: String::from("待たなかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("使う"),
+                    reading: String::from("つかう"),
                     translation: String::from("to use"),
                     word_type: WordType::VerbGodanU,
                 },
@@ -1087,10 +3503,40 @@ mod tests {
                 formal_potential_negative_form: String::from("使えません"),
                 formal_perfective_potential_form: String::from("使えました"),
                 formal_perfective_potential_negative_form: String::from("使えませんでした"),
+                informal_imperative_form: String::from("使え"),
+                informal_imperative_negative_form: String::from("使うな"),
+                formal_imperative_form: String::from("使いなさい"),
+                formal_imperative_negative_form: String::from("使わないでください"),
+                written_imperative_form: String::from("使え"),
+                volitional_form: String::from("使おう"),
+                volitional_negative_form: String::from("使うまい"),
+                formal_volitional_form: String::from("使いましょう"),
+                volitional_negative_variant_form: String::from("使うまい"),
+                te_form: String::from("使って"),
+                negative_te_form: String::from("使わないで"),
+                negative_te_variant_form: String::from("使わなくて"),
+                progressive_form: String::from("使っている"),
+                progressive_negative_form: String::from("使っていない"),
+                perfective_progressive_form: String::from("使っていた"),
+                perfective_progressive_negative_form: String::from("使っていなかった"),
+                formal_progressive_form: String::from("使っています"),
+                formal_progressive_negative_form: String::from("使っていません"),
+                formal_perfective_progressive_form: String::from("使っていました"),
+                formal_perfective_progressive_negative_form: String::from("使っていませんでした"),
+                provisional_form: String::from("使えば"),
+                provisional_negative_form: String::from("使わなければ"),
+                conditional_form: String::from("使ったら"),
+                conditional_negative_form: String::from("使わなかったら"),
+                hypothetical_form: String::from("使うなら"),
+                hypothetical_negative_form: String::from("使わないなら"),
+                formal_volitional_negative_form: String::from("使いますまい"),
+                formal_imperfective_negative_variant_form: String::from("使わないです"),
+                formal_perfective_negative_variant_form: String::from("使わなかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("問う"),
+                    reading: String::from("とう"),
                     translation: String::from("to ask"),
                     word_type: WordType::VerbGodanUS,
                 },
@@ -1110,10 +3556,40 @@ mod tests {
                 formal_potential_negative_form: String::from("問えません"),
                 formal_perfective_potential_form: String::from("問えました"),
                 formal_perfective_potential_negative_form: String::from("問えませんでした"),
+                informal_imperative_form: String::from("問え"),
+                informal_imperative_negative_form: String::from("問うな"),
+                formal_imperative_form: String::from("問いなさい"),
+                formal_imperative_negative_form: String::from("問わないでください"),
+                written_imperative_form: String::from("問え"),
+                volitional_form: String::from("問おう"),
+                volitional_negative_form: String::from("問うまい"),
+                formal_volitional_form: String::from("問いましょう"),
+                volitional_negative_variant_form: String::from("問うまい"),
+                te_form: String::from("問うて"),
+                negative_te_form: String::from("問わないで"),
+                negative_te_variant_form: String::from("問わなくて"),
+                progressive_form: String::from("問うている"),
+                progressive_negative_form: String::from("問うていない"),
+                perfective_progressive_form: String::from("問うていた"),
+                perfective_progressive_negative_form: String::from("問うていなかった"),
+                formal_progressive_form: String::from("問うています"),
+                formal_progressive_negative_form: String::from("問うていません"),
+                formal_perfective_progressive_form: String::from("問うていました"),
+                formal_perfective_progressive_negative_form: String::from("問うていませんでした"),
+                provisional_form: String::from("問えば"),
+                provisional_negative_form: String::from("問わなければ"),
+                conditional_form: String::from("問うたら"),
+                conditional_negative_form: String::from("問わなかったら"),
+                hypothetical_form: String::from("問うなら"),
+                hypothetical_negative_form: String::from("問わないなら"),
+                formal_volitional_negative_form: String::from("問いますまい"),
+                formal_imperfective_negative_variant_form: String::from("問わないです"),
+                formal_perfective_negative_variant_form: String::from("問わなかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("食べる"),
+                    reading: String::from("たべる"),
                     translation: String::from("to eat"),
                     word_type: WordType::VerbIchidan,
                 },
@@ -1135,10 +3611,40 @@ mod tests {
                 formal_potential_negative_form: String::from("食べられません"),
                 formal_perfective_potential_form: String::from("食べられました"),
                 formal_perfective_potential_negative_form: String::from("食べられませんでした"),
+                informal_imperative_form: String::from("食べろ"),
+                informal_imperative_negative_form: String::from("食べるな"),
+                formal_imperative_form: String::from("食べなさい"),
+                formal_imperative_negative_form: String::from("食べないでください"),
+                written_imperative_form: String::from("食べよ"),
+                volitional_form: String::from("食べよう"),
+                volitional_negative_form: String::from("食べるまい"),
+                formal_volitional_form: String::from("食べましょう"),
+                volitional_negative_variant_form: String::from("食べまい"),
+                te_form: String::from("食べて"),
+                negative_te_form: String::from("食べないで"),
+                negative_te_variant_form: String::from("食べなくて"),
+                progressive_form: String::from("食べている"),
+                progressive_negative_form: String::from("食べていない"),
+                perfective_progressive_form: String::from("食べていた"),
+                perfective_progressive_negative_form: String::from("食べていなかった"),
+                formal_progressive_form: String::from("食べています"),
+                formal_progressive_negative_form: String::from("食べていません"),
+                formal_perfective_progressive_form: String::from("食べていました"),
+                formal_perfective_progressive_negative_form: String::from("食べていませんでした"),
+                provisional_form: String::from("食べれば"),
+                provisional_negative_form: String::from("食べなければ"),
+                conditional_form: String::from("食べたら"),
+                conditional_negative_form: String::from("食べなかったら"),
+                hypothetical_form: String::from("食べるなら"),
+                hypothetical_negative_form: String::from("食べないなら"),
+                formal_volitional_negative_form: String::from("食べますまい"),
+                formal_imperfective_negative_variant_form: String::from("食べないです"),
+                formal_perfective_negative_variant_form: String::from("食べなかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("痛い"),
+                    reading: String::from("いたい"),
                     translation: String::from("painful"),
                     word_type: WordType::AdjectiveI,
                 },
@@ -1146,10 +3652,10 @@ mod tests {
                 imperfective_negative_form: String::from("痛くない"),
                 perfective_form: String::from("痛かった"),
                 perfective_negative_form: String::from("痛くなかった"),
-                formal_imperfective_form: String::from(NOT_APPLICABLE),
-                formal_imperfective_negative_form: String::from(NOT_APPLICABLE),
-                formal_perfective_form: String::from(NOT_APPLICABLE),
-                formal_perfective_negative_form: String::from(NOT_APPLICABLE),
+                formal_imperfective_form: String::from("痛いです"),
+                formal_imperfective_negative_form: String::from("痛くありません"),
+                formal_perfective_form: String::from("痛かったです"),
+                formal_perfective_negative_form: String::from("痛くありませんでした"),
                 informal_potential_form: String::from(NOT_APPLICABLE),
                 informal_potential_negative_form: String::from(NOT_APPLICABLE),
                 informal_perfective_potential_form: String::from(NOT_APPLICABLE),
@@ -1158,10 +3664,40 @@ mod tests {
                 formal_potential_negative_form: String::from(NOT_APPLICABLE),
                 formal_perfective_potential_form: String::from(NOT_APPLICABLE),
                 formal_perfective_potential_negative_form: String::from(NOT_APPLICABLE),
+                informal_imperative_form: String::from(NOT_APPLICABLE),
+                informal_imperative_negative_form: String::from(NOT_APPLICABLE),
+                formal_imperative_form: String::from(NOT_APPLICABLE),
+                formal_imperative_negative_form: String::from(NOT_APPLICABLE),
+                written_imperative_form: String::from(NOT_APPLICABLE),
+                volitional_form: String::from("痛かろう"),
+                volitional_negative_form: String::from("痛くあるまい"),
+                formal_volitional_form: String::from(NOT_APPLICABLE),
+                volitional_negative_variant_form: String::from("痛くあるまい"),
+                te_form: String::from("痛くて"),
+                negative_te_form: String::from("痛くなくて"),
+                negative_te_variant_form: String::from("痛くなくて"),
+                progressive_form: String::from(NOT_APPLICABLE),
+                progressive_negative_form: String::from(NOT_APPLICABLE),
+                perfective_progressive_form: String::from(NOT_APPLICABLE),
+                perfective_progressive_negative_form: String::from(NOT_APPLICABLE),
+                formal_progressive_form: String::from(NOT_APPLICABLE),
+                formal_progressive_negative_form: String::from(NOT_APPLICABLE),
+                formal_perfective_progressive_form: String::from(NOT_APPLICABLE),
+                formal_perfective_progressive_negative_form: String::from(NOT_APPLICABLE),
+                provisional_form: String::from("痛ければ"),
+                provisional_negative_form: String::from("痛くなければ"),
+                conditional_form: String::from("痛かったら"),
+                conditional_negative_form: String::from("痛くなかったら"),
+                hypothetical_form: String::from("痛いなら"),
+                hypothetical_negative_form: String::from("痛くないなら"),
+                formal_volitional_negative_form: String::from(NOT_APPLICABLE),
+                formal_imperfective_negative_variant_form: String::from("痛くないです"),
+                formal_perfective_negative_variant_form: String::from("痛くなかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("いい"),
+                    reading: String::from("いい"),
                     translation: String::from("good"),
                     word_type: WordType::AdjectiveI,
                 },
@@ -1169,10 +3705,10 @@ mod tests {
                 imperfective_negative_form: String::from("よくない"),
                 perfective_form: String::from("よかった"),
                 perfective_negative_form: String::from("よくなかった"),
-                formal_imperfective_form: String::from(NOT_APPLICABLE),
-                formal_imperfective_negative_form: String::from(NOT_APPLICABLE),
-                formal_perfective_form: String::from(NOT_APPLICABLE),
-                formal_perfective_negative_form: String::from(NOT_APPLICABLE),
+                formal_imperfective_form: String::from("いいです"),
+                formal_imperfective_negative_form: String::from("よくありません"),
+                formal_perfective_form: String::from("よかったです"),
+                formal_perfective_negative_form: String::from("よくありませんでした"),
                 informal_potential_form: String::from(NOT_APPLICABLE),
                 informal_potential_negative_form: String::from(NOT_APPLICABLE),
                 informal_perfective_potential_form: String::from(NOT_APPLICABLE),
@@ -1181,10 +3717,40 @@ mod tests {
                 formal_potential_negative_form: String::from(NOT_APPLICABLE),
                 formal_perfective_potential_form: String::from(NOT_APPLICABLE),
                 formal_perfective_potential_negative_form: String::from(NOT_APPLICABLE),
+                informal_imperative_form: String::from(NOT_APPLICABLE),
+                informal_imperative_negative_form: String::from(NOT_APPLICABLE),
+                formal_imperative_form: String::from(NOT_APPLICABLE),
+                formal_imperative_negative_form: String::from(NOT_APPLICABLE),
+                written_imperative_form: String::from(NOT_APPLICABLE),
+                volitional_form: String::from("よかろう"),
+                volitional_negative_form: String::from("よくあるまい"),
+                formal_volitional_form: String::from(NOT_APPLICABLE),
+                volitional_negative_variant_form: String::from("よくあるまい"),
+                te_form: String::from("よくて"),
+                negative_te_form: String::from("よくなくて"),
+                negative_te_variant_form: String::from("よくなくて"),
+                progressive_form: String::from(NOT_APPLICABLE),
+                progressive_negative_form: String::from(NOT_APPLICABLE),
+                perfective_progressive_form: String::from(NOT_APPLICABLE),
+                perfective_progressive_negative_form: String::from(NOT_APPLICABLE),
+                formal_progressive_form: String::from(NOT_APPLICABLE),
+                formal_progressive_negative_form: String::from(NOT_APPLICABLE),
+                formal_perfective_progressive_form: String::from(NOT_APPLICABLE),
+                formal_perfective_progressive_negative_form: String::from(NOT_APPLICABLE),
+                provisional_form: String::from("よければ"),
+                provisional_negative_form: String::from("よくなければ"),
+                conditional_form: String::from("よかったら"),
+                conditional_negative_form: String::from("よくなかったら"),
+                hypothetical_form: String::from("いいなら"),
+                hypothetical_negative_form: String::from("よくないなら"),
+                formal_volitional_negative_form: String::from(NOT_APPLICABLE),
+                formal_imperfective_negative_variant_form: String::from("よくないです"),
+                formal_perfective_negative_variant_form: String::from("よくなかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("よい"),
+                    reading: String::from("よい"),
                     translation: String::from("good"),
                     word_type: WordType::AdjectiveI,
                 },
@@ -1192,10 +3758,10 @@ mod tests {
                 imperfective_negative_form: String::from("よくない"),
                 perfective_form: String::from("よかった"),
                 perfective_negative_form: String::from("よくなかった"),
-                formal_imperfective_form: String::from(NOT_APPLICABLE),
-                formal_imperfective_negative_form: String::from(NOT_APPLICABLE),
-                formal_perfective_form: String::from(NOT_APPLICABLE),
-                formal_perfective_negative_form: String::from(NOT_APPLICABLE),
+                formal_imperfective_form: String::from("よいです"),
+                formal_imperfective_negative_form: String::from("よくありません"),
+                formal_perfective_form: String::from("よかったです"),
+                formal_perfective_negative_form: String::from("よくありませんでした"),
                 informal_potential_form: String::from(NOT_APPLICABLE),
                 informal_potential_negative_form: String::from(NOT_APPLICABLE),
                 informal_perfective_potential_form: String::from(NOT_APPLICABLE),
@@ -1204,10 +3770,40 @@ mod tests {
                 formal_potential_negative_form: String::from(NOT_APPLICABLE),
                 formal_perfective_potential_form: String::from(NOT_APPLICABLE),
                 formal_perfective_potential_negative_form: String::from(NOT_APPLICABLE),
+                informal_imperative_form: String::from(NOT_APPLICABLE),
+                informal_imperative_negative_form: String::from(NOT_APPLICABLE),
+                formal_imperative_form: String::from(NOT_APPLICABLE),
+                formal_imperative_negative_form: String::from(NOT_APPLICABLE),
+                written_imperative_form: String::from(NOT_APPLICABLE),
+                volitional_form: String::from("よかろう"),
+                volitional_negative_form: String::from("よくあるまい"),
+                formal_volitional_form: String::from(NOT_APPLICABLE),
+                volitional_negative_variant_form: String::from("よくあるまい"),
+                te_form: String::from("よくて"),
+                negative_te_form: String::from("よくなくて"),
+                negative_te_variant_form: String::from("よくなくて"),
+                progressive_form: String::from(NOT_APPLICABLE),
+                progressive_negative_form: String::from(NOT_APPLICABLE),
+                perfective_progressive_form: String::from(NOT_APPLICABLE),
+                perfective_progressive_negative_form: String::from(NOT_APPLICABLE),
+                formal_progressive_form: String::from(NOT_APPLICABLE),
+                formal_progressive_negative_form: String::from(NOT_APPLICABLE),
+                formal_perfective_progressive_form: String::from(NOT_APPLICABLE),
+                formal_perfective_progressive_negative_form: String::from(NOT_APPLICABLE),
+                provisional_form: String::from("よければ"),
+                provisional_negative_form: String::from("よくなければ"),
+                conditional_form: String::from("よかったら"),
+                conditional_negative_form: String::from("よくなかったら"),
+                hypothetical_form: String::from("よいなら"),
+                hypothetical_negative_form: String::from("よくないなら"),
+                formal_volitional_negative_form: String::from(NOT_APPLICABLE),
+                formal_imperfective_negative_variant_form: String::from("よくないです"),
+                formal_perfective_negative_variant_form: String::from("よくなかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("良い"),
+                    reading: String::from("よい"),
                     translation: String::from("good"),
                     word_type: WordType::AdjectiveI,
                 },
@@ -1215,10 +3811,10 @@ mod tests {
                 imperfective_negative_form: String::from("良くない"),
                 perfective_form: String::from("良かった"),
                 perfective_negative_form: String::from("良くなかった"),
-                formal_imperfective_form: String::from(NOT_APPLICABLE),
-                formal_imperfective_negative_form: String::from(NOT_APPLICABLE),
-                formal_perfective_form: String::from(NOT_APPLICABLE),
-                formal_perfective_negative_form: String::from(NOT_APPLICABLE),
+                formal_imperfective_form: String::from("良いです"),
+                formal_imperfective_negative_form: String::from("良くありません"),
+                formal_perfective_form: String::from("良かったです"),
+                formal_perfective_negative_form: String::from("良くありませんでした"),
                 informal_potential_form: String::from(NOT_APPLICABLE),
                 informal_potential_negative_form: String::from(NOT_APPLICABLE),
                 informal_perfective_potential_form: String::from(NOT_APPLICABLE),
@@ -1227,10 +3823,40 @@ mod tests {
                 formal_potential_negative_form: String::from(NOT_APPLICABLE),
                 formal_perfective_potential_form: String::from(NOT_APPLICABLE),
                 formal_perfective_potential_negative_form: String::from(NOT_APPLICABLE),
+                informal_imperative_form: String::from(NOT_APPLICABLE),
+                informal_imperative_negative_form: String::from(NOT_APPLICABLE),
+                formal_imperative_form: String::from(NOT_APPLICABLE),
+                formal_imperative_negative_form: String::from(NOT_APPLICABLE),
+                written_imperative_form: String::from(NOT_APPLICABLE),
+                volitional_form: String::from("良かろう"),
+                volitional_negative_form: String::from("良くあるまい"),
+                formal_volitional_form: String::from(NOT_APPLICABLE),
+                volitional_negative_variant_form: String::from("良くあるまい"),
+                te_form: String::from("良くて"),
+                negative_te_form: String::from("良くなくて"),
+                negative_te_variant_form: String::from("良くなくて"),
+                progressive_form: String::from(NOT_APPLICABLE),
+                progressive_negative_form: String::from(NOT_APPLICABLE),
+                perfective_progressive_form: String::from(NOT_APPLICABLE),
+                perfective_progressive_negative_form: String::from(NOT_APPLICABLE),
+                formal_progressive_form: String::from(NOT_APPLICABLE),
+                formal_progressive_negative_form: String::from(NOT_APPLICABLE),
+                formal_perfective_progressive_form: String::from(NOT_APPLICABLE),
+                formal_perfective_progressive_negative_form: String::from(NOT_APPLICABLE),
+                provisional_form: String::from("良ければ"),
+                provisional_negative_form: String::from("良くなければ"),
+                conditional_form: String::from("良かったら"),
+                conditional_negative_form: String::from("良くなかったら"),
+                hypothetical_form: String::from("良いなら"),
+                hypothetical_negative_form: String::from("良くないなら"),
+                formal_volitional_negative_form: String::from(NOT_APPLICABLE),
+                formal_imperfective_negative_variant_form: String::from("良くないです"),
+                formal_perfective_negative_variant_form: String::from("良くなかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("簡単"),
+                    reading: String::from("かんたん"),
                     translation: String::from("simple"),
                     word_type: WordType::AdjectiveNa,
                 },
@@ -1238,10 +3864,10 @@ mod tests {
                 imperfective_negative_form: String::from("簡単じゃない"),
                 perfective_form: String::from("簡単だった"),
                 perfective_negative_form: String::from("簡単じゃなかった"),
-                formal_imperfective_form: String::from(NOT_APPLICABLE),
-                formal_imperfective_negative_form: String::from(NOT_APPLICABLE),
-                formal_perfective_form: String::from(NOT_APPLICABLE),
-                formal_perfective_negative_form: String::from(NOT_APPLICABLE),
+                formal_imperfective_form: String::from("簡単です"),
+                formal_imperfective_negative_form: String::from("簡単ではありません"),
+                formal_perfective_form: String::from("簡単でした"),
+                formal_perfective_negative_form: String::from("簡単ではありませんでした"),
                 informal_potential_form: String::from(NOT_APPLICABLE),
                 informal_potential_negative_form: String::from(NOT_APPLICABLE),
                 informal_perfective_potential_form: String::from(NOT_APPLICABLE),
@@ -1250,10 +3876,40 @@ mod tests {
                 formal_potential_negative_form: String::from(NOT_APPLICABLE),
                 formal_perfective_potential_form: String::from(NOT_APPLICABLE),
                 formal_perfective_potential_negative_form: String::from(NOT_APPLICABLE),
+                informal_imperative_form: String::from(NOT_APPLICABLE),
+                informal_imperative_negative_form: String::from(NOT_APPLICABLE),
+                formal_imperative_form: String::from(NOT_APPLICABLE),
+                formal_imperative_negative_form: String::from(NOT_APPLICABLE),
+                written_imperative_form: String::from(NOT_APPLICABLE),
+                volitional_form: String::from("簡単だろう"),
+                volitional_negative_form: String::from("簡単ではあるまい"),
+                formal_volitional_form: String::from(NOT_APPLICABLE),
+                volitional_negative_variant_form: String::from("簡単ではあるまい"),
+                te_form: String::from("簡単で"),
+                negative_te_form: String::from("簡単じゃなくて"),
+                negative_te_variant_form: String::from("簡単じゃなくて"),
+                progressive_form: String::from(NOT_APPLICABLE),
+                progressive_negative_form: String::from(NOT_APPLICABLE),
+                perfective_progressive_form: String::from(NOT_APPLICABLE),
+                perfective_progressive_negative_form: String::from(NOT_APPLICABLE),
+                formal_progressive_form: String::from(NOT_APPLICABLE),
+                formal_progressive_negative_form: String::from(NOT_APPLICABLE),
+                formal_perfective_progressive_form: String::from(NOT_APPLICABLE),
+                formal_perfective_progressive_negative_form: String::from(NOT_APPLICABLE),
+                provisional_form: String::from("簡単なら"),
+                provisional_negative_form: String::from("簡単じゃなければ"),
+                conditional_form: String::from("簡単だったら"),
+                conditional_negative_form: String::from("簡単じゃなかったら"),
+                hypothetical_form: String::from("簡単なら"),
+                hypothetical_negative_form: String::from("簡単じゃないなら"),
+                formal_volitional_negative_form: String::from(NOT_APPLICABLE),
+                formal_imperfective_negative_variant_form: String::from("簡単じゃないです"),
+                formal_perfective_negative_variant_form: String::from("簡単じゃなかったです"),
             },
             TestWordEntity {
                 word_entity: WordEntity {
                     dictionary_form: String::from("簡単な"),
+                    reading: String::from("かんたんな"),
                     translation: String::from("simple"),
                     word_type: WordType::AdjectiveNa,
                 },
@@ -1261,10 +3917,10 @@ mod tests {
                 imperfective_negative_form: String::from("簡単じゃない"),
                 perfective_form: String::from("簡単だった"),
                 perfective_negative_form: String::from("簡単じゃなかった"),
-                formal_imperfective_form: String::from(NOT_APPLICABLE),
-                formal_imperfective_negative_form: String::from(NOT_APPLICABLE),
-                formal_perfective_form: String::from(NOT_APPLICABLE),
-                formal_perfective_negative_form: String::from(NOT_APPLICABLE),
+                formal_imperfective_form: String::from("簡単です"),
+                formal_imperfective_negative_form: String::from("簡単ではありません"),
+                formal_perfective_form: String::from("簡単でした"),
+                formal_perfective_negative_form: String::from("簡単ではありませんでした"),
                 informal_potential_form: String::from(NOT_APPLICABLE),
                 informal_potential_negative_form: String::from(NOT_APPLICABLE),
                 informal_perfective_potential_form: String::from(NOT_APPLICABLE),
@@ -1273,199 +3929,1344 @@ mod tests {
                 formal_potential_negative_form: String::from(NOT_APPLICABLE),
                 formal_perfective_potential_form: String::from(NOT_APPLICABLE),
                 formal_perfective_potential_negative_form: String::from(NOT_APPLICABLE),
+                informal_imperative_form: String::from(NOT_APPLICABLE),
+                informal_imperative_negative_form: String::from(NOT_APPLICABLE),
+                formal_imperative_form: String::from(NOT_APPLICABLE),
+                formal_imperative_negative_form: String::from(NOT_APPLICABLE),
+                written_imperative_form: String::from(NOT_APPLICABLE),
+                volitional_form: String::from("簡単だろう"),
+                volitional_negative_form: String::from("簡単ではあるまい"),
+                formal_volitional_form: String::from(NOT_APPLICABLE),
+                volitional_negative_variant_form: String::from("簡単ではあるまい"),
+                te_form: String::from("簡単で"),
+                negative_te_form: String::from("簡単じゃなくて"),
+                negative_te_variant_form: String::from("簡単じゃなくて"),
+                progressive_form: String::from(NOT_APPLICABLE),
+                progressive_negative_form: String::from(NOT_APPLICABLE),
+                perfective_progressive_form: String::from(NOT_APPLICABLE),
+                perfective_progressive_negative_form: String::from(NOT_APPLICABLE),
+                formal_progressive_form: String::from(NOT_APPLICABLE),
+                formal_progressive_negative_form: String::from(NOT_APPLICABLE),
+                formal_perfective_progressive_form: String::from(NOT_APPLICABLE),
+                formal_perfective_progressive_negative_form: String::from(NOT_APPLICABLE),
+                provisional_form: String::from("簡単なら"),
+                provisional_negative_form: String::from("簡単じゃなければ"),
+                conditional_form: String::from("簡単だったら"),
+                conditional_negative_form: String::from("簡単じゃなかったら"),
+                hypothetical_form: String::from("簡単なら"),
+                hypothetical_negative_form: String::from("簡単じゃないなら"),
+                formal_volitional_negative_form: String::from(NOT_APPLICABLE),
+                formal_imperfective_negative_variant_form: String::from("簡単じゃないです"),
+                formal_perfective_negative_variant_form: String::from("簡単じゃなかったです"),
             },
         ];
     }
 
+    #[test]
+    fn conjugation_test() {
+        let word = &TEST_WORDS[1].word_entity;
+        assert_eq!(
+            word.conjugation(Form::Indicative(Past, Negative, Plain)),
+            Ok(Some(Conjugation {
+                kanji: String::from("準備しなかった"),
+                kana: String::from("じゅんびしなかった"),
+                romaji: String::from("junbishinakatta"),
+            }))
+        );
+        let word = &TEST_WORDS[2].word_entity;
+        assert_eq!(
+            word.conjugation(Form::Potential(NonPast, Affirmative, Polite)),
+            Ok(Some(Conjugation {
+                kanji: String::from("こられます"),
+                kana: String::from("こられます"),
+                romaji: String::from("koraremasu"),
+            }))
+        );
+        let word = &TEST_WORDS[20].word_entity;
+        assert_eq!(
+            word.conjugation(Form::Imperative(Affirmative, Plain)),
+            Ok(None)
+        );
+        let word = &TEST_WORDS[24].word_entity;
+        assert_eq!(
+            word.conjugation(Form::NaiDesu(Past)),
+            Ok(Some(Conjugation {
+                kanji: String::from("簡単じゃなかったです"),
+                kana: String::from("かんたんじゃなかったです"),
+                romaji: String::from("kantanjanakattadesu"),
+            }))
+        );
+    }
+
+    #[test]
+    fn forms_test() {
+        let word = &TEST_WORDS[19].word_entity;
+        assert_eq!(
+            word.forms().unwrap().count(),
+            Form::all()
+                .filter(|form| !form.is_classical() && *form != Form::Adverbial)
+                .count()
+        );
+        assert_eq!(
+            word.forms()
+                .unwrap()
+                .find(|(form, _)| *form == Form::Te(Negative)),
+            Some((Form::Te(Negative), String::from("食べないで")))
+        );
+        // adjectives have no potential, imperative or progressive forms,
+        // only the polite indicative ones
+        let word = &TEST_WORDS[20].word_entity;
+        assert_eq!(word.forms().unwrap().count(), 22);
+        assert!(word
+            .forms()
+            .unwrap()
+            .all(|(_, conjugated)| conjugated != NOT_APPLICABLE));
+    }
+
+    #[test]
+    fn conjugation_error_test() {
+        let word = WordEntity {
+            dictionary_form: String::from("書く"),
+            reading: String::from("かく"),
+            translation: String::from("to write"),
+            word_type: WordType::VerbGodanB,
+        };
+        assert_eq!(
+            word.conjugate(Form::DICTIONARY),
+            Err(ConjugationError::WrongEnding(
+                String::from("書く"),
+                WordType::VerbGodanB
+            ))
+        );
+        assert!(word.passive().is_err());
+        assert!(word.forms().is_err());
+        let word = WordEntity {
+            dictionary_form: String::new(),
+            word_type: WordType::VerbIchidan,
+            ..word
+        };
+        assert_eq!(word.causative_passive(), Err(ConjugationError::EmptyWord));
+        // a reading that does not match is an error as well
+        let word = WordEntity {
+            dictionary_form: String::from("食べる"),
+            reading: String::from("たべ"),
+            ..word
+        };
+        assert!(word.conjugation(Form::DICTIONARY).is_err());
+        assert_eq!(
+            "v9".parse::<WordType>(),
+            Err(ConjugationError::UnsupportedWordType(String::from("v9")))
+        );
+        assert_eq!("v5k-s".parse::<WordType>(), Ok(WordType::VerbGodanKS));
+        assert_eq!(word.trim_string(String::from("る"), "ます"), "");
+        assert_eq!(word.trim_string(String::from("る"), ""), "る");
+    }
+
+    #[test]
+    fn keigo_test() {
+        let keigo = |word: &WordEntity| -> (Option<WordEntity>, Option<WordEntity>) {
+            (word.honorific().unwrap(), word.humble().unwrap())
+        };
+        // suppletive verbs
+        let (honorific, humble) = keigo(&TEST_WORDS[19].word_entity);
+        let honorific = honorific.unwrap();
+        assert_eq!(honorific.dictionary_form, "召し上がる");
+        assert_eq!(honorific.reading, "めしあがる");
+        assert_eq!(
+            honorific.conjugate(Form::Indicative(Past, Affirmative, Polite)),
+            Ok(Some(String::from("召し上がりました")))
+        );
+        assert_eq!(humble.unwrap().dictionary_form, "いただく");
+        let (honorific, humble) = keigo(&TEST_WORDS[9].word_entity);
+        assert_eq!(honorific.unwrap().word_type, WordType::VerbGodanARU);
+        assert_eq!(humble.unwrap().dictionary_form, "参る");
+        // the reading of the keigo verb does not depend on the reading of the word
+        let word = WordEntity {
+            reading: String::from("ゆく"),
+            ..TEST_WORDS[9].word_entity.clone()
+        };
+        assert_eq!(word.humble().unwrap().unwrap().reading, "まいる");
+        let word = WordEntity {
+            reading: String::new(),
+            ..word
+        };
+        assert_eq!(word.humble().unwrap().unwrap().reading, "まいる");
+        // o ni naru and o suru
+        let (honorific, humble) = keigo(&TEST_WORDS[10].word_entity);
+        let honorific = honorific.unwrap();
+        assert_eq!(honorific.dictionary_form, "お読みになる");
+        assert_eq!(
+            honorific.conjugation(Form::Indicative(NonPast, Negative, Polite)),
+            Ok(Some(Conjugation {
+                kanji: String::from("お読みになりません"),
+                kana: String::from("およみになりません"),
+                romaji: String::from("oyomininarimasen"),
+            }))
+        );
+        let humble = humble.unwrap();
+        assert_eq!(humble.dictionary_form, "お読みする");
+        assert_eq!(
+            humble.conjugate(Form::Indicative(Past, Affirmative, Plain)),
+            Ok(Some(String::from("お読みした")))
+        );
+        // suru verbs take nasaru and itasu
+        let (honorific, humble) = keigo(&TEST_WORDS[1].word_entity);
+        assert_eq!(honorific.unwrap().dictionary_form, "準備なさる");
+        assert_eq!(humble.unwrap().reading, "じゅんびいたす");
+        // a one kana stem takes rareru and has no humble form
+        let word = WordEntity {
+            dictionary_form: String::from("出る"),
+            reading: String::from("でる"),
+            translation: String::from("to leave"),
+            word_type: WordType::VerbIchidan,
+        };
+        let (honorific, humble) = keigo(&word);
+        assert_eq!(honorific.unwrap().dictionary_form, "出られる");
+        assert_eq!(humble, None);
+        let (honorific, humble) = keigo(&TEST_WORDS[20].word_entity);
+        assert_eq!((honorific, humble), (None, None));
+        assert!(TEST_WORDS[4].word_entity.honorific().unwrap().is_none());
+    }
+
+    #[test]
+    fn suru_variant_test() {
+        let forms = [
+            Form::Indicative(NonPast, Negative, Plain),
+            Form::Indicative(Past, Affirmative, Polite),
+            Form::Potential(NonPast, Affirmative, Plain),
+            Form::Imperative(Affirmative, Plain),
+            Form::WrittenImperative,
+            Form::Volitional(Affirmative, Plain),
+            Form::Provisional(Affirmative),
+            Form::Hypothetical(Affirmative),
+        ];
+        let conjugated = |word: &WordEntity| -> Vec<String> {
+            forms
+                .iter()
+                .map(|form| word.conjugate(*form).unwrap().unwrap())
+                .collect()
+        };
+        let word = WordEntity {
+            dictionary_form: String::from("察する"),
+            reading: String::from("さっする"),
+            translation: String::from("to guess"),
+            word_type: WordType::VerbSuruS,
+        };
+        assert_eq!(
+            conjugated(&word),
+            [
+                "察しない",
+                "察しました",
+                "察せられる",
+                "察しろ",
+                "察せよ",
+                "察しよう",
+                "察すれば",
+                "察するなら"
+            ]
+        );
+        assert_eq!(
+            word.conjugate(Form::Imperative(Negative, Plain)),
+            Ok(Some(String::from("察するな")))
+        );
+        assert_eq!(
+            word.conjugate(Form::Volitional(Negative, Plain)),
+            Ok(Some(String::from("察するまい")))
+        );
+        assert_eq!(
+            word.conjugate(Form::Te(Negative)),
+            Ok(Some(String::from("察しないで")))
+        );
+        assert_eq!(word.passive().unwrap().unwrap().dictionary_form, "察される");
+        assert_eq!(word.causative().unwrap().unwrap().reading, "さっさせる");
+        let word = WordEntity {
+            dictionary_form: String::from("愛する"),
+            reading: String::from("あいする"),
+            translation: String::from("to love"),
+            ..word
+        };
+        assert_eq!(
+            conjugated(&word),
+            [
+                "愛さない",
+                "愛しました",
+                "愛せる",
+                "愛しろ",
+                "愛せよ",
+                "愛しよう",
+                "愛すれば",
+                "愛するなら"
+            ]
+        );
+        assert_eq!(
+            word.conjugate(Form::Te(Negative)),
+            Ok(Some(String::from("愛さないで")))
+        );
+        assert_eq!(
+            word.conjugation(Form::Potential(NonPast, Affirmative, Plain)),
+            Ok(Some(Conjugation {
+                kanji: String::from("愛せる"),
+                kana: String::from("あいせる"),
+                romaji: String::from("aiseru"),
+            }))
+        );
+        assert_eq!(
+            word.conjugate(Form::Imperative(Negative, Plain)),
+            Ok(Some(String::from("愛するな")))
+        );
+        assert_eq!(
+            word.conjugate(Form::Volitional(Negative, Plain)),
+            Ok(Some(String::from("愛するまい")))
+        );
+        assert_eq!(word.passive().unwrap().unwrap().dictionary_form, "愛される");
+        let word = WordEntity {
+            dictionary_form: String::from("愛す"),
+            reading: String::from("あいす"),
+            word_type: WordType::VerbSuruC,
+            ..word
+        };
+        assert_eq!(
+            word.conjugate(Form::Indicative(NonPast, Negative, Plain)),
+            Ok(Some(String::from("愛さない")))
+        );
+        assert_eq!(
+            word.conjugate(Form::Potential(NonPast, Affirmative, Plain)),
+            Ok(Some(String::from("愛せる")))
+        );
+        let word = WordEntity {
+            dictionary_form: String::from("信ずる"),
+            reading: String::from("しんずる"),
+            translation: String::from("to believe"),
+            word_type: WordType::VerbZuru,
+        };
+        assert_eq!(
+            conjugated(&word),
+            [
+                "信じない",
+                "信じました",
+                "信じられる",
+                "信じろ",
+                "信ぜよ",
+                "信じよう",
+                "信ずれば",
+                "信ずるなら"
+            ]
+        );
+        assert_eq!(
+            word.conjugation(Form::Te(Affirmative)),
+            Ok(Some(Conjugation {
+                kanji: String::from("信じて"),
+                kana: String::from("しんじて"),
+                romaji: String::from("shinjite"),
+            }))
+        );
+        assert_eq!(
+            word.passive().unwrap().unwrap().dictionary_form,
+            "信じられる"
+        );
+        let word = WordEntity {
+            dictionary_form: String::from("信じる"),
+            ..word
+        };
+        assert!(word.conjugate(Form::DICTIONARY).is_err());
+    }
+
+    #[test]
+    fn classical_test() {
+        let classical = |dictionary_form: &str, reading: &str, tag: &str| WordEntity {
+            dictionary_form: String::from(dictionary_form),
+            reading: String::from(reading),
+            translation: String::new(),
+            word_type: tag.parse().unwrap(),
+        };
+        let bases = |word: &WordEntity| -> Vec<String> {
+            Form::all()
+                .filter(|form| form.is_classical())
+                .map(|form| word.conjugate(form).unwrap().unwrap())
+                .collect()
+        };
+        let word = classical("死ぬ", "しぬ", "vn");
+        assert_eq!(
+            bases(&word),
+            [
+                "死な",
+                "死に",
+                "死ぬ",
+                "死ぬる",
+                "死ぬれ",
+                "死ね",
+                "死なず",
+                "死なむ"
+            ]
+        );
+        let word = classical("行く", "ゆく", "v4k");
+        assert_eq!(
+            bases(&word),
+            [
+                "行か",
+                "行き",
+                "行く",
+                "行く",
+                "行け",
+                "行け",
+                "行かず",
+                "行かむ"
+            ]
+        );
+        let word = classical("起く", "おく", "v2k-k");
+        assert_eq!(
+            bases(&word),
+            [
+                "起き",
+                "起き",
+                "起く",
+                "起くる",
+                "起くれ",
+                "起きよ",
+                "起きず",
+                "起きむ"
+            ]
+        );
+        let word = classical("植う", "うう", "v2w-s");
+        assert_eq!(
+            word.conjugation(Form::Classical(Base::Imperative)),
+            Ok(Some(Conjugation {
+                kanji: String::from("植ゑよ"),
+                kana: String::from("うゑよ"),
+                romaji: String::from("uweyo"),
+            }))
+        );
+        let word = classical("有り", "あり", "vr");
+        assert_eq!(
+            bases(&word),
+            [
+                "有ら",
+                "有り",
+                "有り",
+                "有る",
+                "有れ",
+                "有れ",
+                "有らず",
+                "有らむ"
+            ]
+        );
+        let word = classical("高し", "たかし", "adj-ku");
+        assert_eq!(
+            bases(&word),
+            [
+                "高から",
+                "高く",
+                "高し",
+                "高き",
+                "高けれ",
+                "高かれ",
+                "高からず",
+                "高からむ"
+            ]
+        );
+        let word = classical("美し", "うつくし", "adj-shiku");
+        assert_eq!(
+            bases(&word),
+            [
+                "美しから",
+                "美しく",
+                "美し",
+                "美しき",
+                "美しけれ",
+                "美しかれ",
+                "美しからず",
+                "美しからむ"
+            ]
+        );
+        // classical words have no modern forms but the dictionary form
+        assert_eq!(
+            word.conjugate(Form::DICTIONARY),
+            Ok(Some(String::from("美し")))
+        );
+        assert_eq!(word.conjugate(Form::Te(Affirmative)), Ok(None));
+        assert_eq!(word.passive(), Ok(None));
+        assert!(classical("美しい", "うつくしい", "adj-shiku")
+            .conjugate(Form::DICTIONARY)
+            .is_err());
+        // modern words have no bases
+        let word = &TEST_WORDS[9].word_entity;
+        assert_eq!(word.conjugate(Form::ClassicalVolitional), Ok(None));
+    }
+
+    #[test]
+    fn auxiliary_test() {
+        let word = WordEntity {
+            dictionary_form: String::from("堂々"),
+            reading: String::from("どうどう"),
+            translation: String::from("magnificent"),
+            word_type: WordType::AdjectiveTaru,
+        };
+        assert_eq!(
+            word.conjugate(Form::DICTIONARY),
+            Ok(Some(String::from("堂々たる")))
+        );
+        assert_eq!(
+            word.conjugate(Form::Indicative(Past, Negative, Plain)),
+            Ok(Some(String::from("堂々としていなかった")))
+        );
+        assert_eq!(
+            word.conjugate(Form::Te(Affirmative)),
+            Ok(Some(String::from("堂々として")))
+        );
+        assert_eq!(
+            word.conjugate(Form::Adverbial),
+            Ok(Some(String::from("堂々と")))
+        );
+        assert_eq!(word.passive(), Ok(None));
+        let word = WordEntity {
+            dictionary_form: String::from("ゆっくり"),
+            reading: String::from("ゆっくり"),
+            word_type: WordType::AdverbTo,
+            ..word
+        };
+        assert_eq!(
+            word.conjugate(Form::Indicative(NonPast, Affirmative, Polite)),
+            Ok(Some(String::from("ゆっくりとしています")))
+        );
+        let word = WordEntity {
+            dictionary_form: String::from("そうだ"),
+            reading: String::from("そうだ"),
+            word_type: WordType::Auxiliary,
+            ..word
+        };
+        assert_eq!(
+            word.conjugate(Form::Indicative(NonPast, Negative, Plain)),
+            Ok(Some(String::from("そうじゃない")))
+        );
+        assert_eq!(
+            word.conjugate(Form::Indicative(Past, Affirmative, Polite)),
+            Ok(Some(String::from("そうでした")))
+        );
+        let word = WordEntity {
+            dictionary_form: String::from("です"),
+            reading: String::from("です"),
+            ..word
+        };
+        assert_eq!(
+            word.conjugate(Form::DICTIONARY),
+            Ok(Some(String::from("だ")))
+        );
+        assert_eq!(
+            word.conjugate(Form::Indicative(NonPast, Affirmative, Polite)),
+            Ok(Some(String::from("です")))
+        );
+        let word = WordEntity {
+            dictionary_form: String::from("よう"),
+            reading: String::from("よう"),
+            ..word
+        };
+        assert_eq!(
+            word.conjugate(Form::DICTIONARY),
+            Ok(Some(String::from("よう")))
+        );
+        assert_eq!(
+            word.conjugate(Form::Indicative(NonPast, Negative, Plain)),
+            Ok(None)
+        );
+        assert_eq!(word.forms().unwrap().count(), 1);
+        let word = WordEntity {
+            dictionary_form: String::from("たい"),
+            reading: String::from("たい"),
+            word_type: WordType::AuxiliaryAdjective,
+            ..word
+        };
+        assert_eq!(
+            word.conjugate(Form::Indicative(Past, Negative, Plain)),
+            Ok(Some(String::from("たくなかった")))
+        );
+        let word = WordEntity {
+            dictionary_form: String::from("しまう"),
+            reading: String::from("しまう"),
+            word_type: WordType::AuxiliaryVerb,
+            ..word
+        };
+        assert_eq!(
+            word.conjugate(Form::Indicative(Past, Affirmative, Plain)),
+            Ok(Some(String::from("しまった")))
+        );
+        assert_eq!(
+            word.passive().unwrap().unwrap().dictionary_form,
+            "しまわれる"
+        );
+        let word = WordEntity {
+            dictionary_form: String::from("みる"),
+            reading: String::from("みる"),
+            ..word
+        };
+        assert_eq!(
+            word.conjugate(Form::Indicative(Past, Negative, Plain)),
+            Ok(Some(String::from("みなかった")))
+        );
+        let word = WordEntity {
+            dictionary_form: String::from("たがる"),
+            reading: String::from("たがる"),
+            ..word
+        };
+        assert_eq!(
+            word.conjugate(Form::Indicative(NonPast, Negative, Plain)),
+            Ok(Some(String::from("たがらない")))
+        );
+        let word = WordEntity {
+            dictionary_form: String::from("ます"),
+            reading: String::from("ます"),
+            ..word
+        };
+        assert_eq!(
+            word.conjugate(Form::Indicative(NonPast, Negative, Plain)),
+            Ok(Some(String::from("ません")))
+        );
+        assert_eq!(
+            word.conjugate(Form::Volitional(Affirmative, Plain)),
+            Ok(Some(String::from("ましょう")))
+        );
+        assert_eq!(word.conjugate(Form::Te(Affirmative)), Ok(None));
+        assert_eq!(word.passive(), Ok(None));
+        let word = WordEntity {
+            dictionary_form: String::from("な"),
+            reading: String::from("な"),
+            ..word
+        };
+        assert_eq!(
+            word.conjugate(Form::DICTIONARY),
+            Err(ConjugationError::WrongEnding(
+                String::from("な"),
+                WordType::AuxiliaryVerb
+            ))
+        );
+    }
+
+    #[test]
+    fn voice_test() {
+        let voices = [
+            ["される", "させる", "さす", "させられる", NOT_APPLICABLE],
+            [
+                "準備される",
+                "準備させる",
+                "準備さす",
+                "準備させられる",
+                NOT_APPLICABLE,
+            ],
+            [
+                "こられる",
+                "こさせる",
+                "こさす",
+                "こさせられる",
+                NOT_APPLICABLE,
+            ],
+            [
+                "こられる",
+                "こさせる",
+                "こさす",
+                "こさせられる",
+                NOT_APPLICABLE,
+            ],
+            [
+                "下さられる",
+                "下さらせる",
+                "下さらす",
+                "下さらせられる",
+                "下さらされる",
+            ],
+            [
+                "いらっしゃられる",
+                "いらっしゃらせる",
+                "いらっしゃらす",
+                "いらっしゃらせられる",
+                "いらっしゃらされる",
+            ],
+            [
+                "呼ばれる",
+                "呼ばせる",
+                "呼ばす",
+                "呼ばせられる",
+                "呼ばされる",
+            ],
+            [
+                "泳がれる",
+                "泳がせる",
+                "泳がす",
+                "泳がせられる",
+                "泳がされる",
+            ],
+            [
+                "焼かれる",
+                "焼かせる",
+                "焼かす",
+                "焼かせられる",
+                "焼かされる",
+            ],
+            [
+                "行かれる",
+                "行かせる",
+                "行かす",
+                "行かせられる",
+                "行かされる",
+            ],
+            [
+                "読まれる",
+                "読ませる",
+                "読ます",
+                "読ませられる",
+                "読まされる",
+            ],
+            [
+                "死なれる",
+                "死なせる",
+                "死なす",
+                "死なせられる",
+                "死なされる",
+            ],
+            [
+                "走られる",
+                "走らせる",
+                "走らす",
+                "走らせられる",
+                "走らされる",
+            ],
+            [
+                "有られる",
+                "有らせる",
+                "有らす",
+                "有らせられる",
+                "有らされる",
+            ],
+            [
+                "あられる",
+                "あらせる",
+                "あらす",
+                "あらせられる",
+                "あらされる",
+            ],
+            [
+                "示される",
+                "示させる",
+                "示さす",
+                "示させられる",
+                NOT_APPLICABLE,
+            ],
+            [
+                "待たれる",
+                "待たせる",
+                "待たす",
+                "待たせられる",
+                "待たされる",
+            ],
+            [
+                "使われる",
+                "使わせる",
+                "使わす",
+                "使わせられる",
+                "使わされる",
+            ],
+            [
+                "問われる",
+                "問わせる",
+                "問わす",
+                "問わせられる",
+                "問わされる",
+            ],
+            [
+                "食べられる",
+                "食べさせる",
+                "食べさす",
+                "食べさせられる",
+                NOT_APPLICABLE,
+            ],
+            [NOT_APPLICABLE; 5],
+            [NOT_APPLICABLE; 5],
+            [NOT_APPLICABLE; 5],
+            [NOT_APPLICABLE; 5],
+            [NOT_APPLICABLE; 5],
+            [NOT_APPLICABLE; 5],
+        ];
+        for (test_word, voice) in TEST_WORDS.iter().zip(voices.iter()) {
+            let word = &test_word.word_entity;
+            let derived = [
+                word.passive().unwrap(),
+                word.causative().unwrap(),
+                word.short_causative().unwrap(),
+                word.causative_passive().unwrap(),
+                word.short_causative_passive().unwrap(),
+            ];
+            for (derived, expected) in derived.iter().zip(voice.iter()) {
+                let dictionary_form = derived
+                    .as_ref()
+                    .map_or(String::from(NOT_APPLICABLE), |derived| {
+                        derived.dictionary_form.clone()
+                    });
+                assert_eq!(dictionary_form, *expected);
+            }
+        }
+        // the whole conjugation applies on top of the voice
+        let causative_passive = TEST_WORDS[19]
+            .word_entity
+            .causative_passive()
+            .unwrap()
+            .unwrap();
+        assert_eq!(causative_passive.reading, "たべさせられる");
+        assert_eq!(causative_passive.word_type, WordType::VerbIchidan);
+        assert_eq!(
+            causative_passive.conjugation(Form::Indicative(Past, Negative, Plain)),
+            Ok(Some(Conjugation {
+                kanji: String::from("食べさせられなかった"),
+                kana: String::from("たべさせられなかった"),
+                romaji: String::from("tabesaserarenakatta"),
+            }))
+        );
+        let short_causative = TEST_WORDS[8]
+            .word_entity
+            .short_causative()
+            .unwrap()
+            .unwrap();
+        assert_eq!(short_causative.word_type, WordType::VerbGodanS);
+        assert_eq!(short_causative.perfective_form(), "焼かした");
+    }
+
+    #[bench]
+    fn imperfective_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.imperfective_form(),
+                    test_word.imperfective_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn imperfective_negative_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.imperfective_negative_form(),
+                    test_word.imperfective_negative_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn perfective_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.perfective_form(),
+                    test_word.perfective_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn perfective_negative_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.perfective_negative_form(),
+                    test_word.perfective_negative_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn formal_imperfective_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.formal_imperfective_form(),
+                    test_word.formal_imperfective_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn formal_imperfective_negative_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.formal_imperfective_negative_form(),
+                    test_word.formal_imperfective_negative_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn formal_perfective_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.formal_perfective_form(),
+                    test_word.formal_perfective_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn formal_perfective_negative_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.formal_perfective_negative_form(),
+                    test_word.formal_perfective_negative_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn informal_potential_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.informal_potential_form(),
+                    test_word.informal_potential_form,
+                )
+            }
+        })
+    }
+
     #[bench]
-    fn imperfective_form_test(b: &mut Bencher) {
+    fn informal_potential_negative_form_test(b: &mut Bencher) {
         b.iter(|| {
             for test_word in TEST_WORDS.iter() {
                 assert_eq!(
-                    test_word.word_entity.imperfective_form(),
-                    test_word.imperfective_form,
+                    test_word.word_entity.informal_potential_negative_form(),
+                    test_word.informal_potential_negative_form,
                 )
             }
         })
     }
 
     #[bench]
-    fn imperfective_negative_form_test(b: &mut Bencher) {
+    fn informal_perfective_potential_form_test(b: &mut Bencher) {
         b.iter(|| {
             for test_word in TEST_WORDS.iter() {
                 assert_eq!(
-                    test_word.word_entity.imperfective_negative_form(),
-                    test_word.imperfective_negative_form,
+                    test_word.word_entity.informal_perfective_potential_form(),
+                    test_word.informal_perfective_potential_form,
                 )
             }
         })
     }
 
     #[bench]
-    fn perfective_form_test(b: &mut Bencher) {
+    fn informal_perfective_potential_negative_form_test(b: &mut Bencher) {
         b.iter(|| {
             for test_word in TEST_WORDS.iter() {
                 assert_eq!(
-                    test_word.word_entity.perfective_form(),
-                    test_word.perfective_form,
+                    test_word
+                        .word_entity
+                        .informal_perfective_potential_negative_form(),
+                    test_word.informal_perfective_potential_negative_form,
                 )
             }
         })
     }
 
     #[bench]
-    fn perfective_negative_form_test(b: &mut Bencher) {
+    fn formal_potential_form_test(b: &mut Bencher) {
         b.iter(|| {
             for test_word in TEST_WORDS.iter() {
                 assert_eq!(
-                    test_word.word_entity.perfective_negative_form(),
-                    test_word.perfective_negative_form,
+                    test_word.word_entity.formal_potential_form(),
+                    test_word.formal_potential_form,
                 )
             }
         })
     }
 
     #[bench]
-    fn formal_imperfective_form_test(b: &mut Bencher) {
+    fn formal_potential_negative_form_test(b: &mut Bencher) {
         b.iter(|| {
             for test_word in TEST_WORDS.iter() {
                 assert_eq!(
-                    test_word.word_entity.formal_imperfective_form(),
-                    test_word.formal_imperfective_form,
+                    test_word.word_entity.formal_potential_negative_form(),
+                    test_word.formal_potential_negative_form,
                 )
             }
         })
     }
 
     #[bench]
-    fn formal_imperfective_negative_form_test(b: &mut Bencher) {
+    fn formal_perfective_potential_form_test(b: &mut Bencher) {
         b.iter(|| {
             for test_word in TEST_WORDS.iter() {
                 assert_eq!(
-                    test_word.word_entity.formal_imperfective_negative_form(),
-                    test_word.formal_imperfective_negative_form,
+                    test_word.word_entity.formal_perfective_potential_form(),
+                    test_word.formal_perfective_potential_form,
                 )
             }
         })
     }
 
     #[bench]
-    fn formal_perfective_form_test(b: &mut Bencher) {
+    fn formal_perfective_potential_negative_form_test(b: &mut Bencher) {
         b.iter(|| {
             for test_word in TEST_WORDS.iter() {
                 assert_eq!(
-                    test_word.word_entity.formal_perfective_form(),
-                    test_word.formal_perfective_form,
+                    test_word.word_entity.formal_perfective_potential_negative_form(),
+                    test_word.formal_perfective_potential_negative_form,
                 )
             }
         })
     }
 
     #[bench]
-    fn formal_perfective_negative_form_test(b: &mut Bencher) {
+    fn informal_imperative_form_test(b: &mut Bencher) {
         b.iter(|| {
             for test_word in TEST_WORDS.iter() {
                 assert_eq!(
-                    test_word.word_entity.formal_perfective_negative_form(),
-                    test_word.formal_perfective_negative_form,
+                    test_word.word_entity.informal_imperative_form(),
+                    test_word.informal_imperative_form,
                 )
             }
         })
     }
 
     #[bench]
-    fn informal_potential_form_test(b: &mut Bencher) {
+    fn informal_imperative_negative_form_test(b: &mut Bencher) {
         b.iter(|| {
             for test_word in TEST_WORDS.iter() {
                 assert_eq!(
-                    test_word.word_entity.informal_potential_form(),
-                    test_word.informal_potential_form,
+                    test_word.word_entity.informal_imperative_negative_form(),
+                    test_word.informal_imperative_negative_form,
                 )
             }
         })
     }
 
     #[bench]
-    fn informal_potential_negative_form_test(b: &mut Bencher) {
+    fn formal_imperative_form_test(b: &mut Bencher) {
         b.iter(|| {
             for test_word in TEST_WORDS.iter() {
                 assert_eq!(
-                    test_word.word_entity.informal_potential_negative_form(),
-                    test_word.informal_potential_negative_form,
+                    test_word.word_entity.formal_imperative_form(),
+                    test_word.formal_imperative_form,
                 )
             }
         })
     }
 
     #[bench]
-    fn informal_perfective_potential_form_test(b: &mut Bencher) {
+    fn formal_imperative_negative_form_test(b: &mut Bencher) {
         b.iter(|| {
             for test_word in TEST_WORDS.iter() {
                 assert_eq!(
-                    test_word.word_entity.informal_perfective_potential_form(),
-                    test_word.informal_perfective_potential_form,
+                    test_word.word_entity.formal_imperative_negative_form(),
+                    test_word.formal_imperative_negative_form,
                 )
             }
         })
     }
 
     #[bench]
-    fn informal_perfective_potential_negative_form_test(b: &mut Bencher) {
+    fn written_imperative_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.written_imperative_form(),
+                    test_word.written_imperative_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn volitional_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.volitional_form(),
+                    test_word.volitional_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn volitional_negative_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.volitional_negative_form(),
+                    test_word.volitional_negative_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn formal_volitional_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.formal_volitional_form(),
+                    test_word.formal_volitional_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn volitional_negative_variant_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.volitional_negative_variant_form(),
+                    test_word.volitional_negative_variant_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn te_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(test_word.word_entity.te_form(), test_word.te_form,)
+            }
+        })
+    }
+
+    #[bench]
+    fn negative_te_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.negative_te_form(),
+                    test_word.negative_te_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn negative_te_variant_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.negative_te_variant_form(),
+                    test_word.negative_te_variant_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn progressive_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.progressive_form(),
+                    test_word.progressive_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn progressive_negative_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.progressive_negative_form(),
+                    test_word.progressive_negative_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn perfective_progressive_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.perfective_progressive_form(),
+                    test_word.perfective_progressive_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn perfective_progressive_negative_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.perfective_progressive_negative_form(),
+                    test_word.perfective_progressive_negative_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn formal_progressive_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.formal_progressive_form(),
+                    test_word.formal_progressive_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn formal_progressive_negative_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.formal_progressive_negative_form(),
+                    test_word.formal_progressive_negative_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn formal_perfective_progressive_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.formal_perfective_progressive_form(),
+                    test_word.formal_perfective_progressive_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn formal_perfective_progressive_negative_form_test(b: &mut Bencher) {
         b.iter(|| {
             for test_word in TEST_WORDS.iter() {
                 assert_eq!(
                     test_word
                         .word_entity
-                        .informal_perfective_potential_negative_form(),
-                    test_word.informal_perfective_potential_negative_form,
+                        .formal_perfective_progressive_negative_form(),
+                    test_word.formal_perfective_progressive_negative_form,
                 )
             }
         })
     }
 
     #[bench]
-    fn formal_potential_form_test(b: &mut Bencher) {
+    fn provisional_form_test(b: &mut Bencher) {
         b.iter(|| {
             for test_word in TEST_WORDS.iter() {
                 assert_eq!(
-                    test_word.word_entity.formal_potential_form(),
-                    test_word.formal_potential_form,
+                    test_word.word_entity.provisional_form(),
+                    test_word.provisional_form,
                 )
             }
         })
     }
 
     #[bench]
-    fn formal_potential_negative_form_test(b: &mut Bencher) {
+    fn provisional_negative_form_test(b: &mut Bencher) {
         b.iter(|| {
             for test_word in TEST_WORDS.iter() {
                 assert_eq!(
-                    test_word.word_entity.formal_potential_negative_form(),
-                    test_word.formal_potential_negative_form,
+                    test_word.word_entity.provisional_negative_form(),
+                    test_word.provisional_negative_form,
                 )
             }
         })
     }
 
     #[bench]
-    fn formal_perfective_potential_form_test(b: &mut Bencher) {
+    fn conditional_form_test(b: &mut Bencher) {
         b.iter(|| {
             for test_word in TEST_WORDS.iter() {
                 assert_eq!(
-                    test_word.word_entity.formal_perfective_potential_form(),
-                    test_word.formal_perfective_potential_form,
+                    test_word.word_entity.conditional_form(),
+                    test_word.conditional_form,
                 )
             }
         })
     }
 
     #[bench]
-    fn formal_perfective_potential_negative_form_test(b: &mut Bencher) {
+    fn conditional_negative_form_test(b: &mut Bencher) {
         b.iter(|| {
             for test_word in TEST_WORDS.iter() {
                 assert_eq!(
-                    test_word.word_entity.formal_perfective_potential_negative_form(),
-                    test_word.formal_perfective_potential_negative_form,
+                    test_word.word_entity.conditional_negative_form(),
+                    test_word.conditional_negative_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn hypothetical_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.hypothetical_form(),
+                    test_word.hypothetical_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn hypothetical_negative_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.hypothetical_negative_form(),
+                    test_word.hypothetical_negative_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn formal_volitional_negative_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word.word_entity.formal_volitional_negative_form(),
+                    test_word.formal_volitional_negative_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn formal_imperfective_negative_variant_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word
+                        .word_entity
+                        .formal_imperfective_negative_variant_form(),
+                    test_word.formal_imperfective_negative_variant_form,
+                )
+            }
+        })
+    }
+
+    #[bench]
+    fn formal_perfective_negative_variant_form_test(b: &mut Bencher) {
+        b.iter(|| {
+            for test_word in TEST_WORDS.iter() {
+                assert_eq!(
+                    test_word
+                        .word_entity
+                        .formal_perfective_negative_variant_form(),
+                    test_word.formal_perfective_negative_variant_form,
                 )
             }
         })
@@ -1,7 +1,9 @@
-use katsuyou;
+use katsuyou::searcher::{search_word, Result};
 
 #[test]
 fn it_runs() {
-    // katsuyou::run();
-    assert!(true);
+    match search_word("書く") {
+        Result::Single(word) => assert_eq!(word.translation, "to write"),
+        _ => panic!("書く should be found once"),
+    }
 }